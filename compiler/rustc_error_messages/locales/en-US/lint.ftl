@@ -438,3 +438,9 @@ lint_opaque_hidden_inferred_bound = opaque type `{$ty}` does not satisfy its ass
     .specifically = this associated type bound is unsatisfied for `{$proj_ty}`
 
 lint_opaque_hidden_inferred_bound_sugg = add this bound
+
+lint_unnecessary_const_evaluatable_bound = this bound doesn't depend on any generic parameters, so it is always satisfied
+    .suggestion = remove this bound
+
+lint_invariant_raw_pointer_field = `{$ty}` is invariant in `{$param}` because of the `*mut` pointer in this field
+    .note = use `*const` or `NonNull` instead of `*mut` if `{$ty}` isn't meant to be invariant in `{$param}`
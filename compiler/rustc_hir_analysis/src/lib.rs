@@ -117,6 +117,8 @@
 use astconv::AstConv;
 use bounds::Bounds;
 
+pub use variance::relate_substs_by_variance;
+
 fn require_c_abi_if_c_variadic(tcx: TyCtxt<'_>, decl: &hir::FnDecl<'_>, abi: Abi, span: Span) {
     const ERROR_HEAD: &str = "C-variadic function must have a compatible calling convention";
     const CONVENTIONS_UNSTABLE: &str = "`C`, `cdecl`, `win64`, `sysv64` or `efiapi`";
@@ -511,6 +513,12 @@ pub fn check_crate(tcx: TyCtxt<'_>) -> Result<(), ErrorGuaranteed> {
         });
     })?;
 
+    // `variances_of` doesn't go through the crate-wide inference, so run it here
+    // to dump all of its constraints.
+    if tcx.sess.opts.unstable_opts.dump_variance_constraints {
+        tcx.ensure().crate_variances(());
+    }
+
     if tcx.features().rustc_attrs {
         tcx.sess.track_errors(|| {
             tcx.sess.time("variance_testing", || variance::test::test_variance(tcx));
@@ -4,6 +4,7 @@
 //! We walk the set of items and, for each member, generate new constraints.
 
 use hir::def_id::{DefId, LocalDefId};
+use rustc_arena::DroplessArena;
 use rustc_hir as hir;
 use rustc_hir::def::DefKind;
 use rustc_middle::ty::subst::{GenericArgKind, SubstsRef};
@@ -22,6 +23,11 @@ pub struct ConstraintContext<'a, 'tcx> {
     bivariant: VarianceTermPtr<'a>,
 
     pub constraints: Vec<Constraint<'a>>,
+
+    // When set, the local items whose variances the constraints depend on, but
+    // which are not part of this inference, are recorded here rather than looked
+    // up with `variances_of`. See `items_used_by_item`.
+    used_items: Option<Vec<LocalDefId>>,
 }
 
 /// Declares that the variable `decl_id` appears in a location with
@@ -30,6 +36,8 @@ pub struct ConstraintContext<'a, 'tcx> {
 pub struct Constraint<'a> {
     pub inferred: InferredIndex,
     pub variance: &'a VarianceTerm<'a>,
+    /// The item whose contents gave rise to this constraint.
+    pub source: LocalDefId,
 }
 
 /// To build constraints, we visit one item (type, trait) at a time
@@ -42,50 +50,75 @@ pub struct Constraint<'a> {
 /// then while we are visiting `Bar<T>`, the `CurrentItem` would have
 /// the `DefId` and the start of `Foo`'s inferreds.
 pub struct CurrentItem {
+    def_id: LocalDefId,
     inferred_start: InferredIndex,
 }
 
-pub fn add_constraints_from_crate<'a, 'tcx>(
+/// Builds the constraints on the generics of the single item `def_id`.
+pub fn add_constraints_from_item<'a, 'tcx>(
     terms_cx: TermsContext<'a, 'tcx>,
+    def_id: LocalDefId,
 ) -> ConstraintContext<'a, 'tcx> {
-    let tcx = terms_cx.tcx;
-    let covariant = terms_cx.arena.alloc(ConstantTerm(ty::Covariant));
-    let contravariant = terms_cx.arena.alloc(ConstantTerm(ty::Contravariant));
-    let invariant = terms_cx.arena.alloc(ConstantTerm(ty::Invariant));
-    let bivariant = terms_cx.arena.alloc(ConstantTerm(ty::Bivariant));
-    let mut constraint_cx = ConstraintContext {
-        terms_cx,
-        covariant,
-        contravariant,
-        invariant,
-        bivariant,
-        constraints: Vec::new(),
-    };
-
-    let crate_items = tcx.hir_crate_items(());
-
-    for def_id in crate_items.definitions() {
-        let def_kind = tcx.def_kind(def_id);
-        match def_kind {
-            DefKind::Struct | DefKind::Union | DefKind::Enum => {
-                constraint_cx.build_constraints_for_item(def_id);
-
-                let adt = tcx.adt_def(def_id);
-                for variant in adt.variants() {
-                    if let Some(ctor_def_id) = variant.ctor_def_id() {
-                        constraint_cx.build_constraints_for_item(ctor_def_id.expect_local());
-                    }
-                }
-            }
-            DefKind::Fn | DefKind::AssocFn => constraint_cx.build_constraints_for_item(def_id),
-            _ => {}
-        }
+    let mut constraint_cx = ConstraintContext::new(terms_cx);
+    constraint_cx.build_constraints_for_item(def_id);
+    constraint_cx
+}
+
+/// Builds the constraints on the generics of `items`, which are inferred together.
+pub fn add_constraints_from_items<'a, 'tcx>(
+    terms_cx: TermsContext<'a, 'tcx>,
+    items: &[LocalDefId],
+) -> ConstraintContext<'a, 'tcx> {
+    let mut constraint_cx = ConstraintContext::new(terms_cx);
+    for &def_id in items {
+        constraint_cx.build_constraints_for_item(def_id);
     }
+    constraint_cx
+}
 
+/// Like `add_constraints_from_item`, but only looks at the uses of the generics
+/// of `def_id` within `ty`, rather than at the whole item.
+pub fn add_constraints_from_ty_in_item<'a, 'tcx>(
+    terms_cx: TermsContext<'a, 'tcx>,
+    def_id: LocalDefId,
+    ty: Ty<'tcx>,
+) -> ConstraintContext<'a, 'tcx> {
+    let mut constraint_cx = ConstraintContext::new(terms_cx);
+    let inferred_start = constraint_cx.terms_cx.inferred_starts[&def_id];
+    let current_item = &CurrentItem { def_id, inferred_start };
+    constraint_cx.add_constraints_from_ty(current_item, ty, constraint_cx.covariant);
     constraint_cx
 }
 
+/// The local items whose variances the constraints on the generics of `def_id`
+/// depend on. As these are found while building the constraints, they are exactly
+/// the items whose variances `add_constraints_from_item` would look up.
+pub fn items_used_by_item(tcx: TyCtxt<'_>, def_id: LocalDefId) -> Vec<LocalDefId> {
+    let arena = DroplessArena::default();
+    let terms_cx = determine_parameters_for_item(tcx, &arena, def_id);
+    let mut constraint_cx = ConstraintContext::new(terms_cx);
+    constraint_cx.used_items = Some(vec![]);
+    constraint_cx.build_constraints_for_item(def_id);
+    constraint_cx.used_items.unwrap_or_default()
+}
+
 impl<'a, 'tcx> ConstraintContext<'a, 'tcx> {
+    fn new(terms_cx: TermsContext<'a, 'tcx>) -> Self {
+        let covariant = terms_cx.arena.alloc(ConstantTerm(ty::Covariant));
+        let contravariant = terms_cx.arena.alloc(ConstantTerm(ty::Contravariant));
+        let invariant = terms_cx.arena.alloc(ConstantTerm(ty::Invariant));
+        let bivariant = terms_cx.arena.alloc(ConstantTerm(ty::Bivariant));
+        ConstraintContext {
+            terms_cx,
+            covariant,
+            contravariant,
+            invariant,
+            bivariant,
+            constraints: Vec::new(),
+            used_items: None,
+        }
+    }
+
     fn tcx(&self) -> TyCtxt<'tcx> {
         self.terms_cx.tcx
     }
@@ -100,7 +133,14 @@ fn build_constraints_for_item(&mut self, def_id: LocalDefId) {
         }
 
         let inferred_start = self.terms_cx.inferred_starts[&def_id];
-        let current_item = &CurrentItem { inferred_start };
+        let current_item = &CurrentItem { def_id, inferred_start };
+
+        // The generics of a type alias are used as they appear in the aliased type.
+        if let DefKind::TyAlias = tcx.def_kind(def_id) {
+            self.add_constraints_from_ty(current_item, tcx.type_of(def_id), self.covariant);
+            return;
+        }
+
         match tcx.type_of(def_id).kind() {
             ty::Adt(def, _) => {
                 // Not entirely obvious: constraints on structs/enums do not
@@ -137,9 +177,56 @@ fn add_constraint(&mut self, current: &CurrentItem, index: u32, variance: Varian
         self.constraints.push(Constraint {
             inferred: InferredIndex(current.inferred_start.0 + index as usize),
             variance,
+            source: current.def_id,
         });
     }
 
+    /// Emits the gathered constraints as notes, for `-Z dump-variance-constraints`:
+    /// one for each item they come from, pointing at it.
+    pub fn dump(&self) {
+        let tcx = self.tcx();
+
+        // Name each inferred after the generic parameter it stands for.
+        let mut params = vec![String::new(); self.terms_cx.inferred_terms.len()];
+        for (&def_id, &InferredIndex(start)) in self.terms_cx.inferred_starts.iter() {
+            let generics = tcx.generics_of(def_id);
+            let item = tcx.def_path_str(def_id.to_def_id());
+            for index in 0..generics.count() {
+                let name = generics.param_at(index, tcx).name;
+                params[start + index] = format!("{name}@{item}");
+            }
+        }
+
+        let mut constraints = self.constraints.iter().peekable();
+        while let Some(&&Constraint { source, .. }) = constraints.peek() {
+            let item = tcx.def_path_str(source.to_def_id());
+            let mut diag =
+                tcx.sess.struct_note_without_error(format!("variance constraints from `{item}`"));
+            diag.set_span(tcx.def_span(source));
+            while let Some(constraint) = constraints.next_if(|c| c.source == source) {
+                let InferredIndex(inferred) = constraint.inferred;
+                diag.note(format!(
+                    "term {} <= term {}",
+                    params[inferred],
+                    Self::describe_term(constraint.variance, &params),
+                ));
+            }
+            diag.emit();
+        }
+    }
+
+    fn describe_term(term: VarianceTermPtr<'a>, params: &[String]) -> String {
+        match *term {
+            ConstantTerm(v) => format!("{:?}", v),
+            TransformTerm(t1, t2) => format!(
+                "({} \u{00D7} {})",
+                Self::describe_term(t1, params),
+                Self::describe_term(t2, params)
+            ),
+            InferredTerm(InferredIndex(index)) => params[index].clone(),
+        }
+    }
+
     fn contravariant(&mut self, variance: VarianceTermPtr<'a>) -> VarianceTermPtr<'a> {
         self.xform(variance, self.contravariant)
     }
@@ -324,9 +411,22 @@ fn add_constraints_from_substs(
             return;
         }
 
-        let (local, remote) = if let Some(def_id) = def_id.as_local() {
-            (Some(self.terms_cx.inferred_starts[&def_id]), None)
+        let local_start =
+            def_id.as_local().and_then(|def_id| self.terms_cx.inferred_starts.get(&def_id));
+        let (local, remote) = if let Some(&start) = local_start {
+            (Some(start), None)
+        } else if let Some(used_items) = &mut self.used_items
+            && let Some(def_id) = def_id.as_local()
+        {
+            // Only the use of `def_id` matters here, not its variances, which may
+            // depend on those being inferred: leave its parameters invariant.
+            used_items.push(def_id);
+            (None, None)
         } else {
+            // Either defined in another crate, or not part of this inference (when
+            // inferring the variances of a type alias on its own, or of the items
+            // of one component, see `variances_of`): in both cases the variance
+            // is already known.
             (None, Some(self.tcx().variances_of(def_id)))
         };
 
@@ -336,10 +436,12 @@ fn add_constraints_from_substs(
                 // variance not yet inferred, so return a symbolic
                 // variance.
                 self.terms_cx.inferred_terms[start + i]
-            } else {
+            } else if let Some(remote) = remote {
                 // Parameter on an item defined within another crate:
                 // variance already inferred, just look it up.
-                self.constant_term(remote.as_ref().unwrap()[i])
+                self.constant_term(remote[i])
+            } else {
+                self.invariant
             };
             let variance_i = self.xform(variance, variance_decl);
             debug!(
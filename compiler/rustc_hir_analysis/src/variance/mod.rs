@@ -4,10 +4,20 @@
 //! [rustc dev guide]: https://rustc-dev-guide.rust-lang.org/variance.html
 
 use rustc_arena::DroplessArena;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::graph::scc::Sccs;
+use rustc_data_structures::graph::vec_graph::VecGraph;
 use rustc_hir::def::DefKind;
 use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::Mutability;
+use rustc_middle::ty::fold::BottomUpFolder;
 use rustc_middle::ty::query::Providers;
-use rustc_middle::ty::{self, CrateVariancesMap, TyCtxt, TypeSuperVisitable, TypeVisitable};
+use rustc_middle::ty::subst::SubstsRef;
+use rustc_middle::ty::{self, CrateVariancesMap, Ty, TyCtxt, TypeFoldable};
+use rustc_middle::ty::{TypeSuperVisitable, TypeVisitable};
+use rustc_span::symbol::Symbol;
+use rustc_span::Span;
+use std::iter;
 use std::ops::ControlFlow;
 
 /// Defines the `TermsContext` basically houses an arena where we can
@@ -27,16 +37,122 @@
 mod xform;
 
 pub fn provide(providers: &mut Providers) {
-    *providers = Providers { variances_of, crate_variances, ..*providers };
+    *providers = Providers {
+        variances_of,
+        variances_with_params_of,
+        crate_variances,
+        variance_components,
+        variance_component_of,
+        component_variances,
+        crate_invariant_params,
+        phantom_data_variance_conflicts,
+        raw_pointer_invariant_params,
+        opaque_lifetime_capture_spans,
+        ..*providers
+    };
 }
 
 fn crate_variances(tcx: TyCtxt<'_>, (): ()) -> CrateVariancesMap<'_> {
+    let components = tcx.variance_components(());
+    if tcx.sess.opts.unstable_opts.dump_variance_constraints {
+        for items in &components.components {
+            let arena = DroplessArena::default();
+            let terms_cx = terms::determine_parameters_for_items(tcx, &arena, items);
+            constraints::add_constraints_from_items(terms_cx, items).dump();
+        }
+    }
+    let variances = components
+        .components
+        .iter()
+        .flat_map(|items| tcx.component_variances(items[0]).variances.iter())
+        .map(|(&def_id, &variances)| (def_id, variances))
+        .collect();
+    CrateVariancesMap { variances }
+}
+
+fn variance_components(tcx: TyCtxt<'_>, (): ()) -> ty::VarianceComponents {
+    // The items whose variances are inferred, see `variances_of`.
+    let mut items = vec![];
+    for def_id in tcx.hir_crate_items(()).definitions() {
+        match tcx.def_kind(def_id) {
+            DefKind::Struct | DefKind::Union | DefKind::Enum => {
+                items.push(def_id);
+                for variant in tcx.adt_def(def_id).variants() {
+                    if let Some(ctor_def_id) = variant.ctor_def_id() {
+                        items.push(ctor_def_id.expect_local());
+                    }
+                }
+            }
+            DefKind::Fn | DefKind::AssocFn => items.push(def_id),
+            _ => {}
+        }
+    }
+    items.retain(|&def_id| tcx.generics_of(def_id).count() > 0);
+
+    // There is an edge from each item to the items whose variances its own depend on.
+    let node_of: FxHashMap<LocalDefId, usize> =
+        items.iter().enumerate().map(|(node, &def_id)| (def_id, node)).collect();
+    let edges = items
+        .iter()
+        .enumerate()
+        .flat_map(|(node, &def_id)| {
+            constraints::items_used_by_item(tcx, def_id)
+                .into_iter()
+                .filter_map(|used| Some((node, *node_of.get(&used)?)))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    let sccs: Sccs<usize, usize> = Sccs::new(&VecGraph::new(items.len(), edges));
+
+    // The SCCs are numbered in post order, so a component comes after those it uses.
+    let mut components = vec![vec![]; sccs.num_sccs()];
+    for (node, &def_id) in items.iter().enumerate() {
+        components[sccs.scc(node)].push(def_id);
+    }
+    for items in &mut components {
+        items.sort_unstable_by_key(|def_id| def_id.local_def_index);
+    }
+    let component_of =
+        items.iter().enumerate().map(|(node, &def_id)| (def_id, sccs.scc(node))).collect();
+    ty::VarianceComponents { component_of, components }
+}
+
+fn variance_component_of(tcx: TyCtxt<'_>, item_def_id: LocalDefId) -> &[LocalDefId] {
+    let components = tcx.variance_components(());
+    match components.component_of.get(&item_def_id) {
+        Some(&component) => &components.components[component],
+        None => &[],
+    }
+}
+
+fn component_variances(tcx: TyCtxt<'_>, root: LocalDefId) -> CrateVariancesMap<'_> {
+    let items = tcx.variance_component_of(root);
     let arena = DroplessArena::default();
-    let terms_cx = terms::determine_parameters_to_be_inferred(tcx, &arena);
-    let constraints_cx = constraints::add_constraints_from_crate(terms_cx);
+    let terms_cx = terms::determine_parameters_for_items(tcx, &arena, items);
+    let constraints_cx = constraints::add_constraints_from_items(terms_cx, items);
     solve::solve_constraints(constraints_cx)
 }
 
+fn crate_invariant_params(tcx: TyCtxt<'_>, (): ()) -> Vec<(DefId, Vec<u32>)> {
+    let crate_map = tcx.crate_variances(());
+    let mut items: Vec<_> = crate_map
+        .variances
+        .iter()
+        .filter_map(|(&def_id, variances)| {
+            let params: Vec<u32> = variances
+                .iter()
+                .enumerate()
+                .filter(|(_, &v)| matches!(v, ty::Invariant | ty::Bivariant))
+                .map(|(index, _)| index as u32)
+                .collect();
+            (!params.is_empty()).then_some((def_id, params))
+        })
+        .collect();
+    // The map is not ordered, sort so the result does not depend on hashing.
+    items.sort_unstable_by_key(|&(def_id, _)| def_id.index);
+    items
+}
+
 fn variances_of(tcx: TyCtxt<'_>, item_def_id: DefId) -> &[ty::Variance] {
     // Skip items with no generics - there's nothing to infer in them.
     if tcx.generics_of(item_def_id).count() == 0 {
@@ -49,48 +165,265 @@ fn variances_of(tcx: TyCtxt<'_>, item_def_id: DefId) -> &[ty::Variance] {
         | DefKind::Enum
         | DefKind::Struct
         | DefKind::Union
-        | DefKind::Variant
         | DefKind::Ctor(..) => {}
+        DefKind::Variant => {
+            // Variants are not part of the inference, only their constructors are.
+            return &[];
+        }
         DefKind::OpaqueTy | DefKind::ImplTraitPlaceholder => {
             return variance_of_opaque(tcx, item_def_id.expect_local());
         }
+        DefKind::AssocTy => {
+            // Projections relate all their arguments invariantly, see the `Relate` impl
+            // of `ProjectionTy`, so nothing is inferred here: neither the bounds of an
+            // associated type nor the types impls define it as can make it variant.
+            // This includes the parameters of generic associated types.
+            let count = tcx.generics_of(item_def_id).count();
+            return tcx.arena.alloc_from_iter(iter::repeat(ty::Invariant).take(count));
+        }
         _ => {
             // Variance not relevant.
             span_bug!(tcx.def_span(item_def_id), "asked to compute variance for wrong kind of item")
         }
     }
 
-    // Everything else must be inferred.
+    // Everything else must be inferred. Rather than going through `crate_variances`,
+    // which depends on every item of the crate, only look at the variances of the
+    // items which depend on each other's, so that changing one item doesn't invalidate
+    // the variances of the items unrelated to it.
+    let Some(&root) = tcx.variance_component_of(item_def_id.expect_local()).first() else {
+        return &[];
+    };
+    tcx.component_variances(root).variances.get(&item_def_id).copied().unwrap_or(&[])
+}
 
-    let crate_map = tcx.crate_variances(());
-    crate_map.variances.get(&item_def_id).copied().unwrap_or(&[])
+/// Pairs the index of each generic argument of `substs_a` and `substs_b`, two
+/// instantiations of the generics of `def_id`, with the variance of its parameter.
+/// The arguments aren't related here: for `substs_a` to be a subtype of `substs_b`,
+/// the arguments of covariant parameters have to be subtypes, those of contravariant
+/// ones supertypes and those of invariant ones equal, while those of bivariant ones
+/// don't matter. Parameters whose variance isn't inferred, like those of enum
+/// variants, are treated as invariant. Returns `None` if `def_id` is not an item
+/// with variances, like a trait or a type alias.
+///
+/// This is meant for tools comparing instantiations of an item, e.g. to find out
+/// whether changing the type of a public item is a breaking change.
+pub fn relate_substs_by_variance<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    def_id: DefId,
+    substs_a: SubstsRef<'tcx>,
+    substs_b: SubstsRef<'tcx>,
+) -> Option<Vec<(usize, ty::Variance)>> {
+    assert_eq!(substs_a.len(), substs_b.len(), "instantiations of different items");
+    match tcx.def_kind(def_id) {
+        DefKind::Fn
+        | DefKind::AssocFn
+        | DefKind::Enum
+        | DefKind::Struct
+        | DefKind::Union
+        | DefKind::Variant
+        | DefKind::Ctor(..)
+        | DefKind::OpaqueTy
+        | DefKind::ImplTraitPlaceholder => {}
+        _ => return None,
+    }
+    let variances = tcx.variances_of(def_id);
+    Some(
+        (0..substs_a.len())
+            .map(|index| (index, variances.get(index).copied().unwrap_or(ty::Invariant)))
+            .collect(),
+    )
+}
+
+fn variances_with_params_of(
+    tcx: TyCtxt<'_>,
+    def_id: DefId,
+) -> &[(Symbol, ty::GenericParamDefKind, ty::Variance)] {
+    let variances = tcx.variances_of(def_id);
+    if variances.is_empty() {
+        return &[];
+    }
+
+    // `param_at` looks up the parameters of the parent generics, whose indices
+    // come before those of the item's own parameters.
+    let generics = tcx.generics_of(def_id);
+    tcx.arena.alloc_from_iter(variances.iter().enumerate().map(|(index, &variance)| {
+        let param = generics.param_at(index, tcx);
+        (param.name, param.kind, variance)
+    }))
+}
+
+fn opaque_lifetime_capture_spans(tcx: TyCtxt<'_>, item_def_id: LocalDefId) -> &[Option<Span>] {
+    let collector = collect_opaque_lifetimes(tcx, item_def_id);
+    tcx.arena.alloc_from_iter(collector.capture_spans.into_iter())
+}
+
+/// Infers the variances of the generics of the type alias `def_id`, as they are
+/// used in the aliased type. Type aliases are not part of `crate_variances`, so
+/// this runs the inference for the alias alone.
+pub(crate) fn variances_of_type_alias(tcx: TyCtxt<'_>, def_id: LocalDefId) -> Vec<ty::Variance> {
+    let arena = DroplessArena::default();
+    let terms_cx = terms::determine_parameters_for_item(tcx, &arena, def_id);
+    let constraints_cx = constraints::add_constraints_from_item(terms_cx, def_id);
+    let map = solve::solve_constraints(constraints_cx);
+    map.variances.get(&def_id.to_def_id()).map_or_else(Vec::new, |variances| variances.to_vec())
+}
+
+/// Infers the variances of the generics of `def_id` from their uses in `ty` alone.
+fn variances_in_ty<'tcx>(tcx: TyCtxt<'tcx>, def_id: LocalDefId, ty: Ty<'tcx>) -> Vec<ty::Variance> {
+    let arena = DroplessArena::default();
+    let terms_cx = terms::determine_parameters_for_item(tcx, &arena, def_id);
+    let constraints_cx = constraints::add_constraints_from_ty_in_item(terms_cx, def_id, ty);
+    let map = solve::solve_constraints(constraints_cx);
+    map.variances.get(&def_id.to_def_id()).map_or_else(Vec::new, |variances| variances.to_vec())
+}
+
+fn phantom_data_variance_conflicts(tcx: TyCtxt<'_>, def_id: LocalDefId) -> &[(u32, Span, Span)] {
+    let variances = tcx.variances_of(def_id);
+    if variances.is_empty() {
+        return &[];
+    }
+
+    // The first `PhantomData` field using each parameter covariantly, resp.
+    // contravariantly, and whether some field alone makes it invariant.
+    let mut covariant = vec![None; variances.len()];
+    let mut contravariant = vec![None; variances.len()];
+    let mut invariant = vec![false; variances.len()];
+    for field in tcx.adt_def(def_id).all_fields() {
+        let field_ty = tcx.type_of(field.did);
+        let is_phantom_data = matches!(field_ty.kind(), ty::Adt(def, _) if def.is_phantom_data());
+        for (index, variance) in variances_in_ty(tcx, def_id, field_ty).into_iter().enumerate() {
+            match variance {
+                ty::Covariant if is_phantom_data => {
+                    covariant[index].get_or_insert(tcx.def_span(field.did));
+                }
+                ty::Contravariant if is_phantom_data => {
+                    contravariant[index].get_or_insert(tcx.def_span(field.did));
+                }
+                ty::Invariant => invariant[index] = true,
+                _ => {}
+            }
+        }
+    }
+
+    tcx.arena.alloc_from_iter(variances.iter().enumerate().filter_map(|(index, &variance)| match (
+        covariant[index],
+        contravariant[index],
+    ) {
+        (Some(co), Some(contra)) if variance == ty::Invariant && !invariant[index] => {
+            Some((index as u32, co, contra))
+        }
+        _ => None,
+    }))
+}
+
+fn raw_pointer_invariant_params<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    def_id: LocalDefId,
+) -> &'tcx [(u32, DefId)] {
+    let variances = tcx.variances_of(def_id);
+    if !variances.contains(&ty::Invariant) {
+        return &[];
+    }
+
+    let mut_to_const = |ty: Ty<'tcx>| {
+        ty.fold_with(&mut BottomUpFolder {
+            tcx,
+            ty_op: |ty| match *ty.kind() {
+                ty::RawPtr(ty::TypeAndMut { ty, mutbl: Mutability::Mut }) => tcx.mk_imm_ptr(ty),
+                _ => ty,
+            },
+            lt_op: |lt| lt,
+            ct_op: |ct| ct,
+        })
+    };
+
+    // The variances the ADT would have if its `*mut` pointers were `*const`: the
+    // fields of a tuple are all used covariantly, like those of the ADT.
+    let fields: Vec<_> = tcx.adt_def(def_id).all_fields().map(|field| field.did).collect();
+    let const_ptr_fields = tcx.mk_tup(fields.iter().map(|&did| mut_to_const(tcx.type_of(did))));
+    let const_ptr_variances = variances_in_ty(tcx, def_id, const_ptr_fields);
+    if !iter::zip(variances, &const_ptr_variances)
+        .any(|(&variance, &const_ptr)| variance == ty::Invariant && const_ptr != ty::Invariant)
+    {
+        return &[];
+    }
+
+    // The variances of each field, as is and with `*const` pointers, computed once
+    // for all the parameters.
+    let field_variances: Vec<_> = fields
+        .iter()
+        .map(|&did| {
+            let field_ty = tcx.type_of(did);
+            (
+                did,
+                variances_in_ty(tcx, def_id, field_ty),
+                variances_in_ty(tcx, def_id, mut_to_const(field_ty)),
+            )
+        })
+        .collect();
+
+    tcx.arena.alloc_from_iter(variances.iter().enumerate().filter_map(|(index, &variance)| {
+        if variance != ty::Invariant || const_ptr_variances[index] == ty::Invariant {
+            return None;
+        }
+        // Point at a field which is only invariant because of its pointers.
+        let &(field, ..) = field_variances.iter().find(|(_, variances, const_ptr_variances)| {
+            variances[index] == ty::Invariant && const_ptr_variances[index] != ty::Invariant
+        })?;
+        Some((index as u32, field))
+    }))
 }
 
 #[instrument(level = "trace", skip(tcx), ret)]
 fn variance_of_opaque(tcx: TyCtxt<'_>, item_def_id: LocalDefId) -> &[ty::Variance] {
-    let generics = tcx.generics_of(item_def_id);
+    let collector = collect_opaque_lifetimes(tcx, item_def_id);
+    tcx.arena.alloc_from_iter(collector.variances.into_iter())
+}
 
-    // Opaque types may only use regions that are bound. So for
-    // ```rust
-    // type Foo<'a, 'b, 'c> = impl Trait<'a> + 'b;
-    // ```
-    // we may not use `'c` in the hidden type.
-    struct OpaqueTypeLifetimeCollector {
-        variances: Vec<ty::Variance>,
-    }
+// Opaque types may only use regions that are bound. So for
+// ```rust
+// type Foo<'a, 'b, 'c> = impl Trait<'a> + 'b;
+// ```
+// we may not use `'c` in the hidden type.
+struct OpaqueTypeLifetimeCollector {
+    variances: Vec<ty::Variance>,
+    /// For each lifetime made invariant by appearing in the substs of a trait
+    /// bound, the span of the first such bound.
+    capture_spans: Vec<Option<Span>>,
+    /// The span of the bound currently being visited, if lifetimes found in it
+    /// should be recorded in `capture_spans`.
+    bound_span: Option<Span>,
+}
 
-    impl<'tcx> ty::TypeVisitor<'tcx> for OpaqueTypeLifetimeCollector {
-        #[instrument(level = "trace", skip(self), ret)]
-        fn visit_region(&mut self, r: ty::Region<'tcx>) -> ControlFlow<Self::BreakTy> {
-            if let ty::RegionKind::ReEarlyBound(ebr) = r.kind() {
-                self.variances[ebr.index as usize] = ty::Invariant;
+impl<'tcx> ty::TypeVisitor<'tcx> for OpaqueTypeLifetimeCollector {
+    #[instrument(level = "trace", skip(self), ret)]
+    fn visit_region(&mut self, r: ty::Region<'tcx>) -> ControlFlow<Self::BreakTy> {
+        if let ty::RegionKind::ReEarlyBound(ebr) = r.kind() {
+            let idx = ebr.index as usize;
+            self.variances[idx] = ty::Invariant;
+            if let Some(span) = self.bound_span && self.capture_spans[idx].is_none() {
+                self.capture_spans[idx] = Some(span);
             }
-            r.super_visit_with(self)
         }
+        r.super_visit_with(self)
     }
+}
+
+fn collect_opaque_lifetimes(
+    tcx: TyCtxt<'_>,
+    item_def_id: LocalDefId,
+) -> OpaqueTypeLifetimeCollector {
+    let generics = tcx.generics_of(item_def_id);
 
     // By default, RPIT are invariant wrt type and const generics, but they are bivariant wrt
     // lifetime generics.
+    //
+    // Unlike lifetimes, the type and const parameters of the parent generics are always
+    // captured, whether the bounds mention them or not, so the hidden type may use them:
+    // the opaque type of `fn foo<const N: usize>() -> impl Sized { [0u8; N] }` is
+    // `[u8; 1]` for `foo::<1>` and `[u8; 2]` for `foo::<2>`. Making `N` bivariant would
+    // make these two opaque types equal, so they must stay invariant.
     let mut variances: Vec<_> = std::iter::repeat(ty::Invariant).take(generics.count()).collect();
 
     // Mark all lifetimes from parent generics as unused (Bivariant).
@@ -111,9 +444,11 @@ fn visit_region(&mut self, r: ty::Region<'tcx>) -> ControlFlow<Self::BreakTy> {
         }
     }
 
-    let mut collector = OpaqueTypeLifetimeCollector { variances };
+    let capture_spans = vec![None; variances.len()];
+    let mut collector = OpaqueTypeLifetimeCollector { variances, capture_spans, bound_span: None };
     let id_substs = ty::InternalSubsts::identity_for_item(tcx, item_def_id.to_def_id());
     for pred in tcx.bound_explicit_item_bounds(item_def_id.to_def_id()).transpose_iter() {
+        let span = pred.0.1;
         let pred = pred.map_bound(|(pred, _)| *pred).subst(tcx, id_substs);
         debug!(?pred);
 
@@ -122,23 +457,36 @@ fn visit_region(&mut self, r: ty::Region<'tcx>) -> ControlFlow<Self::BreakTy> {
         // type Foo<'a> = impl PartialEq<Foo<'a>>;
         // which thus mentions `'a` and should thus accept hidden types that borrow 'a
         // instead of requiring an additional `+ 'a`.
+        //
+        // This doesn't look at the bounds of the opaque types mentioned in `pred`, only
+        // at their substs, so it terminates even when opaque types mention each other,
+        // as in `type A<'a> = impl PartialEq<B<'a>>; type B<'a> = impl PartialEq<A<'a>>;`.
+        //
+        // Lifetimes in the substs of a bound are invariant even when they only appear
+        // in covariant positions such as `impl Trait<&'a u8>`: the hidden type only
+        // implements `Trait<&'a u8>` for that exact `'a`, and trait parameters are
+        // invariant, so `impl Trait<&'long u8>` can't be used as `impl Trait<&'short u8>`.
         match pred.kind().skip_binder() {
             ty::PredicateKind::Clause(ty::Clause::Trait(ty::TraitPredicate {
                 trait_ref: ty::TraitRef { def_id: _, substs },
                 constness: _,
                 polarity: _,
             })) => {
+                collector.bound_span = Some(span);
                 for subst in &substs[1..] {
                     subst.visit_with(&mut collector);
                 }
+                collector.bound_span = None;
             }
             ty::PredicateKind::Clause(ty::Clause::Projection(ty::ProjectionPredicate {
                 projection_ty: ty::ProjectionTy { substs, item_def_id: _ },
                 term,
             })) => {
+                collector.bound_span = Some(span);
                 for subst in &substs[1..] {
                     subst.visit_with(&mut collector);
                 }
+                collector.bound_span = None;
                 term.visit_with(&mut collector);
             }
             ty::PredicateKind::Clause(ty::Clause::TypeOutlives(ty::OutlivesPredicate(
@@ -152,5 +500,8 @@ fn visit_region(&mut self, r: ty::Region<'tcx>) -> ControlFlow<Self::BreakTy> {
             }
         }
     }
-    tcx.arena.alloc_from_iter(collector.variances.into_iter())
+    collector
 }
+
+#[cfg(test)]
+mod tests;
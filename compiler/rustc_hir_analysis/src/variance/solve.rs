@@ -6,7 +6,7 @@
 //! inferred is then written into the `variance_map` in the tcx.
 
 use rustc_data_structures::fx::FxHashMap;
-use rustc_hir::def_id::DefId;
+use rustc_hir::def_id::{DefId, LocalDefId};
 use rustc_middle::ty;
 
 use super::constraints::*;
@@ -20,6 +20,9 @@ struct SolveContext<'a, 'tcx> {
 
     // Maps from an InferredIndex to the inferred value for that variable.
     solutions: Vec<ty::Variance>,
+
+    // The number of solutions which changed in each round of `solve`.
+    changes_per_round: Vec<usize>,
 }
 
 pub fn solve_constraints<'tcx>(
@@ -29,14 +32,22 @@ pub fn solve_constraints<'tcx>(
 
     let mut solutions = vec![ty::Bivariant; terms_cx.inferred_terms.len()];
     for &(id, ref variances) in &terms_cx.lang_items {
-        let InferredIndex(start) = terms_cx.inferred_starts[&id];
+        // Skip the lang items which are not part of this inference.
+        let Some(&InferredIndex(start)) = terms_cx.inferred_starts.get(&id) else { continue };
         for (i, &variance) in variances.iter().enumerate() {
             solutions[start + i] = variance;
         }
     }
 
-    let mut solutions_cx = SolveContext { terms_cx, constraints, solutions };
+    let mut solutions_cx =
+        SolveContext { terms_cx, constraints, solutions, changes_per_round: vec![] };
     solutions_cx.solve();
+    if solutions_cx.terms_cx.tcx.sess.opts.unstable_opts.variance_solver_stats {
+        solutions_cx.dump_stats();
+    }
+    if solutions_cx.terms_cx.tcx.sess.opts.unstable_opts.validate_variance {
+        solutions_cx.validate();
+    }
     let variances = solutions_cx.create_map();
 
     ty::CrateVariancesMap { variances }
@@ -52,9 +63,10 @@ fn solve(&mut self) {
         let mut changed = true;
         while changed {
             changed = false;
+            let mut changes = 0;
 
             for constraint in &self.constraints {
-                let Constraint { inferred, variance: term } = *constraint;
+                let Constraint { inferred, variance: term, .. } = *constraint;
                 let InferredIndex(inferred) = inferred;
                 let variance = self.evaluate(term);
                 let old_value = self.solutions[inferred];
@@ -68,11 +80,56 @@ fn solve(&mut self) {
 
                     self.solutions[inferred] = new_value;
                     changed = true;
+                    changes += 1;
                 }
             }
+
+            self.changes_per_round.push(changes);
+        }
+    }
+
+    /// Notes the number of rounds `solve` took, for `-Z variance-solver-stats`.
+    fn dump_stats(&self) {
+        let tcx = self.terms_cx.tcx;
+        // The same items may be solved several times, e.g. by `variances_in_ty`, so
+        // name them to tell the lines apart.
+        let mut items: Vec<_> = self.terms_cx.inferred_starts.keys().copied().collect();
+        items.sort_unstable_by_key(|def_id| def_id.local_def_index);
+        let items: Vec<_> = items
+            .iter()
+            .map(|def_id| format!("`{}`", tcx.def_path_str(def_id.to_def_id())))
+            .collect();
+        let rounds = self.changes_per_round.len();
+        tcx.sess.note_without_error(format!(
+            "variance solver for {}: {} terms, {} constraints, {} rounds, changes per round: {:?}",
+            items.join(", "),
+            self.solutions.len(),
+            self.constraints.len(),
+            rounds,
+            self.changes_per_round,
+        ));
+
+        // Each round but the last changes at least one solution, which can only
+        // change twice, so more rounds mean that the constraints are broken.
+        let max_rounds = 2 * self.solutions.len() + 1;
+        if rounds > max_rounds {
+            tcx.sess.warn(&format!(
+                "variance inference took {rounds} rounds, more than the {max_rounds} it \
+                 should take at most"
+            ));
         }
     }
 
+    /// Checks that the solutions satisfy every constraint, i.e. that `solve` reached
+    /// a fixed point, for `-Z validate-variance`. This has to run before `create_map`
+    /// makes the variances of const and unused fn parameters invariant.
+    fn validate(&self) {
+        let tcx = self.terms_cx.tcx;
+        validate_solutions(&self.constraints, &self.solutions, |def_id| {
+            tcx.def_path_str(def_id.to_def_id())
+        });
+    }
+
     fn enforce_const_invariance(&self, generics: &ty::Generics, variances: &mut [ty::Variance]) {
         let tcx = self.terms_cx.tcx;
 
@@ -120,16 +177,43 @@ fn create_map(&self) -> FxHashMap<DefId, &'tcx [ty::Variance]> {
     }
 
     fn evaluate(&self, term: VarianceTermPtr<'a>) -> ty::Variance {
-        match *term {
-            ConstantTerm(v) => v,
+        evaluate(&self.solutions, term)
+    }
+}
 
-            TransformTerm(t1, t2) => {
-                let v1 = self.evaluate(t1);
-                let v2 = self.evaluate(t2);
-                v1.xform(v2)
-            }
+fn evaluate(solutions: &[ty::Variance], term: VarianceTermPtr<'_>) -> ty::Variance {
+    match *term {
+        ConstantTerm(v) => v,
+
+        TransformTerm(t1, t2) => {
+            let v1 = evaluate(solutions, t1);
+            let v2 = evaluate(solutions, t2);
+            v1.xform(v2)
+        }
+
+        InferredTerm(InferredIndex(index)) => solutions[index],
+    }
+}
 
-            InferredTerm(InferredIndex(index)) => self.solutions[index],
+/// Calls `bug!` if some of the `solutions` don't satisfy the `constraints`, naming
+/// the item a constraint comes from with `item_name`.
+pub(super) fn validate_solutions(
+    constraints: &[Constraint<'_>],
+    solutions: &[ty::Variance],
+    item_name: impl Fn(LocalDefId) -> String,
+) {
+    for constraint in constraints {
+        let Constraint { inferred: InferredIndex(inferred), variance: term, source } = *constraint;
+        let required = evaluate(solutions, term);
+        let solution = solutions[inferred];
+        if glb(required, solution) != solution {
+            bug!(
+                "inferred {} was solved to {:?}, but a constraint from `{}` requires {:?}",
+                inferred,
+                solution,
+                item_name(source),
+                required,
+            );
         }
     }
 }
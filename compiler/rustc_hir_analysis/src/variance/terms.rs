@@ -10,7 +10,6 @@
 // a variable.
 
 use rustc_arena::DroplessArena;
-use rustc_hir::def::DefKind;
 use rustc_hir::def_id::{LocalDefId, LocalDefIdMap};
 use rustc_middle::ty::{self, TyCtxt};
 use std::fmt;
@@ -61,9 +60,22 @@ pub struct TermsContext<'a, 'tcx> {
     pub inferred_terms: Vec<VarianceTermPtr<'a>>,
 }
 
-pub fn determine_parameters_to_be_inferred<'a, 'tcx>(
+/// Builds the inferreds for the single item `def_id`. Used to infer the variances
+/// of items on their own, like type aliases.
+pub fn determine_parameters_for_item<'a, 'tcx>(
     tcx: TyCtxt<'tcx>,
     arena: &'a DroplessArena,
+    def_id: LocalDefId,
+) -> TermsContext<'a, 'tcx> {
+    determine_parameters_for_items(tcx, arena, &[def_id])
+}
+
+/// Builds the inferreds for `items`, whose variances are inferred together. Used
+/// for the items of one component of `variance_components`, see `variances_of`.
+pub fn determine_parameters_for_items<'a, 'tcx>(
+    tcx: TyCtxt<'tcx>,
+    arena: &'a DroplessArena,
+    items: &[LocalDefId],
 ) -> TermsContext<'a, 'tcx> {
     let mut terms_cx = TermsContext {
         tcx,
@@ -73,34 +85,9 @@ pub fn determine_parameters_to_be_inferred<'a, 'tcx>(
 
         lang_items: lang_items(tcx),
     };
-
-    // See the following for a discussion on dep-graph management.
-    //
-    // - https://rustc-dev-guide.rust-lang.org/query.html
-    // - https://rustc-dev-guide.rust-lang.org/variance.html
-    let crate_items = tcx.hir_crate_items(());
-
-    for def_id in crate_items.definitions() {
-        debug!("add_inferreds for item {:?}", def_id);
-
-        let def_kind = tcx.def_kind(def_id);
-
-        match def_kind {
-            DefKind::Struct | DefKind::Union | DefKind::Enum => {
-                terms_cx.add_inferreds_for_item(def_id);
-
-                let adt = tcx.adt_def(def_id);
-                for variant in adt.variants() {
-                    if let Some(ctor_def_id) = variant.ctor_def_id() {
-                        terms_cx.add_inferreds_for_item(ctor_def_id.expect_local());
-                    }
-                }
-            }
-            DefKind::Fn | DefKind::AssocFn => terms_cx.add_inferreds_for_item(def_id),
-            _ => {}
-        }
+    for &def_id in items {
+        terms_cx.add_inferreds_for_item(def_id);
     }
-
     terms_cx
 }
 
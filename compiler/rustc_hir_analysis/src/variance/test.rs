@@ -1,15 +1,98 @@
-use rustc_errors::struct_span_err;
-use rustc_middle::ty::TyCtxt;
+use rustc_ast::NestedMetaItem;
+use rustc_errors::{struct_span_err, Diagnostic, MultiSpan};
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::LocalDefId;
+use rustc_middle::ty::{self, TyCtxt};
 use rustc_span::symbol::sym;
 
+use super::variances_of_type_alias;
+
 pub fn test_variance(tcx: TyCtxt<'_>) {
     // For unit testing: check for a special "rustc_variance"
     // attribute and report an error with various results if found.
-    for id in tcx.hir().items() {
-        if tcx.has_attr(id.owner_id.to_def_id(), sym::rustc_variance) {
-            let variances_of = tcx.variances_of(id.owner_id);
-            struct_span_err!(tcx.sess, tcx.def_span(id.owner_id), E0208, "{:?}", variances_of)
-                .emit();
+    // Associated fns are included, their variances starting with
+    // those of the parameters of their trait or impl.
+    let crate_items = tcx.hir_crate_items(());
+    let owners = crate_items
+        .items()
+        .map(|id| id.owner_id)
+        .chain(crate_items.trait_items().map(|id| id.owner_id))
+        .chain(crate_items.impl_items().map(|id| id.owner_id));
+    for owner_id in owners {
+        let Some(attr) = tcx.get_attr(owner_id.to_def_id(), sym::rustc_variance) else {
+            continue;
+        };
+        let span = tcx.def_span(owner_id);
+        let def_kind = tcx.def_kind(owner_id);
+        let variances = if let DefKind::TyAlias = def_kind {
+            variances_of_type_alias(tcx, owner_id.def_id)
+        } else {
+            tcx.variances_of(owner_id).to_vec()
+        };
+
+        // `#[rustc_variance(T, a)]` only reports the variances of the given parameters,
+        // lifetimes being named without their `'`.
+        let mut err = if let Some(params) = attr.meta_item_list() {
+            let named = named_variances(tcx, owner_id.def_id, &params, &variances);
+            if named.is_empty() {
+                continue;
+            }
+            struct_span_err!(tcx.sess, span, E0208, "{}", named)
+        } else {
+            struct_span_err!(tcx.sess, span, E0208, "{:?}", variances)
+        };
+        if let DefKind::Struct | DefKind::Enum | DefKind::Union = def_kind {
+            note_phantom_data_conflicts(tcx, owner_id.def_id, &mut err);
         }
+        err.emit();
+    }
+}
+
+/// Formats the variances of the parameters named in `params` as `T: o, 'a: +`.
+fn named_variances(
+    tcx: TyCtxt<'_>,
+    def_id: LocalDefId,
+    params: &[NestedMetaItem],
+    variances: &[ty::Variance],
+) -> String {
+    let generics = tcx.generics_of(def_id);
+    let mut named = vec![];
+    for item in params {
+        let Some(ident) = item.ident() else {
+            tcx.sess.span_err(item.span(), "expected the name of a generic parameter");
+            continue;
+        };
+        // Also look at the parent's parameters, e.g. the `Self` of a trait method.
+        let param =
+            (0..generics.count()).map(|index| generics.param_at(index, tcx)).find(|param| {
+                param.name.as_str().strip_prefix('\'').unwrap_or(param.name.as_str())
+                    == ident.as_str()
+            });
+        match param {
+            Some(param) => {
+                named.push(format!("{}: {:?}", param.name, variances[param.index as usize]))
+            }
+            None => {
+                tcx.sess.span_err(ident.span, &format!("no generic parameter named `{ident}`"));
+            }
+        }
+    }
+    named.join(", ")
+}
+
+fn note_phantom_data_conflicts(tcx: TyCtxt<'_>, def_id: LocalDefId, err: &mut Diagnostic) {
+    let generics = tcx.generics_of(def_id);
+    for &(index, co, contra) in tcx.phantom_data_variance_conflicts(def_id) {
+        let name = generics.param_at(index as usize, tcx).name;
+        let mut spans = MultiSpan::from_spans(vec![co, contra]);
+        spans.push_span_label(co, "used covariantly here");
+        spans.push_span_label(contra, "used contravariantly here");
+        err.span_note(
+            spans,
+            &format!(
+                "`{name}` is invariant because `PhantomData` fields use it both \
+                 covariantly and contravariantly"
+            ),
+        );
     }
 }
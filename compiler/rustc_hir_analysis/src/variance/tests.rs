@@ -0,0 +1,50 @@
+use super::constraints::Constraint;
+use super::solve::validate_solutions;
+use super::terms::InferredIndex;
+use super::terms::VarianceTerm::*;
+use rustc_hir::def_id::CRATE_DEF_ID;
+use rustc_middle::ty;
+
+fn item_name(_: rustc_hir::def_id::LocalDefId) -> String {
+    "Foo".to_string()
+}
+
+#[test]
+fn consistent_solutions() {
+    let contravariant = ConstantTerm(ty::Contravariant);
+    let inferred = InferredTerm(InferredIndex(0));
+    let through_inferred = TransformTerm(&contravariant, &inferred);
+    let constraints = [
+        Constraint { inferred: InferredIndex(0), variance: &contravariant, source: CRATE_DEF_ID },
+        Constraint {
+            inferred: InferredIndex(1),
+            variance: &through_inferred,
+            source: CRATE_DEF_ID,
+        },
+    ];
+    validate_solutions(&constraints, &[ty::Contravariant, ty::Covariant], item_name);
+    validate_solutions(&constraints, &[ty::Invariant, ty::Invariant], item_name);
+}
+
+#[test]
+#[should_panic(expected = "inferred 0 was solved to +, but a constraint from `Foo` requires -")]
+fn inconsistent_solutions() {
+    let contravariant = ConstantTerm(ty::Contravariant);
+    let constraints =
+        [Constraint { inferred: InferredIndex(0), variance: &contravariant, source: CRATE_DEF_ID }];
+    validate_solutions(&constraints, &[ty::Covariant], item_name);
+}
+
+#[test]
+#[should_panic(expected = "inferred 1 was solved to -, but a constraint from `Foo` requires +")]
+fn inconsistent_transformed_solutions() {
+    let contravariant = ConstantTerm(ty::Contravariant);
+    let inferred = InferredTerm(InferredIndex(0));
+    let through_inferred = TransformTerm(&contravariant, &inferred);
+    let constraints = [Constraint {
+        inferred: InferredIndex(1),
+        variance: &through_inferred,
+        source: CRATE_DEF_ID,
+    }];
+    validate_solutions(&constraints, &[ty::Contravariant, ty::Contravariant], item_name);
+}
@@ -5,7 +5,8 @@
 //! - `#[rustc_clean(cfg="rev2", except="typeck")]` if we are
 //!   in `#[cfg(rev2)]`, then the fingerprints associated with
 //!   `DepNode::typeck(X)` must be DIFFERENT (`X` is the `DefId` of the
-//!   current node).
+//!   current node). Queries about the whole crate, like `crate_variances`,
+//!   can be listed in `except` for any node, as any item can affect them.
 //! - `#[rustc_clean(cfg="rev2")]` same as above, except that the
 //!   fingerprints must be the SAME (along with all other fingerprints).
 //!
@@ -21,7 +22,7 @@
 
 use rustc_ast::{self as ast, Attribute, NestedMetaItem};
 use rustc_data_structures::fx::FxHashSet;
-use rustc_hir::def_id::LocalDefId;
+use rustc_hir::def_id::{DefPathHash, LocalDefId};
 use rustc_hir::intravisit;
 use rustc_hir::Node as HirNode;
 use rustc_hir::{ImplItemKind, ItemKind as HirItem, TraitItemKind};
@@ -196,8 +197,9 @@ fn assertion_auto(&mut self, item_id: LocalDefId, attr: &Attribute) -> Assertion
         let (name, mut auto) = self.auto_labels(item_id, attr);
         let except = self.except(attr);
         let loaded_from_disk = self.loaded_from_disk(attr);
+        let def_path_hash = self.tcx.def_path_hash(item_id.to_def_id());
         for e in except.iter() {
-            if !auto.remove(e) {
+            if !auto.remove(e) && !self.is_crate_wide(e, def_path_hash) {
                 let msg = format!(
                     "`except` specified DepNodes that can not be affected for \"{}\": \"{}\"",
                     name, e
@@ -208,6 +210,12 @@ fn assertion_auto(&mut self, item_id: LocalDefId, attr: &Attribute) -> Assertion
         Assertion { clean: auto, dirty: except, loaded_from_disk }
     }
 
+    /// Whether `label` is that of a query about the whole crate rather than an item.
+    fn is_crate_wide(&self, label: &str, def_path_hash: DefPathHash) -> bool {
+        DepNode::from_label_string(self.tcx, label, def_path_hash)
+            .map_or(false, |dep_node| dep_node.extract_def_id(self.tcx).is_none())
+    }
+
     /// `loaded_from_disk=` attribute value
     fn loaded_from_disk(&self, attr: &Attribute) -> Labels {
         for item in attr.meta_item_list().unwrap_or_else(Vec::new) {
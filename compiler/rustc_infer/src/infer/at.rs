@@ -71,6 +71,10 @@ pub fn fork(&self) -> Self {
             lexical_region_resolutions: self.lexical_region_resolutions.clone(),
             selection_cache: self.selection_cache.clone(),
             evaluation_cache: self.evaluation_cache.clone(),
+            // The fork starts with empty const caches, so that what was cached for the
+            // inference context it is forked from isn't reused in a different state.
+            const_eval_failure_cache: Default::default(),
+            const_evaluatable_cache: Default::default(),
             reported_trait_errors: self.reported_trait_errors.clone(),
             reported_closure_mismatch: self.reported_closure_mismatch.clone(),
             tainted_by_errors: self.tainted_by_errors.clone(),
@@ -270,7 +270,8 @@ pub fn unexpected_hidden_region_diagnostic<'tcx>(
     hidden_region: ty::Region<'tcx>,
     opaque_ty: ty::OpaqueTypeKey<'tcx>,
 ) -> DiagnosticBuilder<'tcx, ErrorGuaranteed> {
-    let opaque_ty = tcx.mk_opaque(opaque_ty.def_id.to_def_id(), opaque_ty.substs);
+    let opaque_def_id = opaque_ty.def_id;
+    let opaque_ty = tcx.mk_opaque(opaque_def_id.to_def_id(), opaque_ty.substs);
     let mut err = struct_span_err!(
         tcx.sess,
         span,
@@ -336,6 +337,29 @@ pub fn unexpected_hidden_region_diagnostic<'tcx>(
         }
     }
 
+    // Point at the bounds which made the opaque type capture lifetimes; the hidden
+    // type may only capture those.
+    let generics = tcx.generics_of(opaque_def_id);
+    let mut captures: FxIndexMap<Span, Vec<String>> = FxIndexMap::default();
+    for (index, span) in tcx.opaque_lifetime_capture_spans(opaque_def_id).iter().enumerate() {
+        if let Some(span) = *span {
+            let name = generics.param_at(index, tcx).name;
+            captures.entry(span).or_default().push(format!("`{name}`"));
+        }
+    }
+    for (span, names) in captures {
+        err.span_note(
+            span,
+            &format!(
+                "lifetime{} {} {} captured because {} in this bound",
+                pluralize!(names.len()),
+                names.join(", "),
+                pluralize!("is", names.len()),
+                if names.len() == 1 { "it appears" } else { "they appear" },
+            ),
+        );
+    }
+
     err
 }
 
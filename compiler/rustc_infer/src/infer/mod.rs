@@ -252,6 +252,12 @@ pub enum DefiningAnchor {
     Error,
 }
 
+pub type ConstEvalFailureCache<'tcx> =
+    RefCell<FxHashSet<Canonical<'tcx, ty::ParamEnvAnd<'tcx, ty::UnevaluatedConst<'tcx>>>>>;
+
+pub type ConstEvaluatableCache<'tcx> =
+    RefCell<FxHashSet<Canonical<'tcx, ty::ParamEnvAnd<'tcx, ty::Const<'tcx>>>>>;
+
 pub struct InferCtxt<'tcx> {
     pub tcx: TyCtxt<'tcx>,
 
@@ -291,6 +297,18 @@ pub struct InferCtxt<'tcx> {
     /// Caches the results of trait evaluation.
     pub evaluation_cache: select::EvaluationCache<'tcx>,
 
+    /// The constants without inference variables, other than region variables,
+    /// which `const_eval_resolve` found to be too generic to evaluate, as trait
+    /// selection may try to evaluate the same constant many times while
+    /// backtracking. As they don't mention inference variables, the entries stay
+    /// valid when a snapshot is rolled back. Forks start with an empty cache.
+    pub const_eval_failure_cache: ConstEvalFailureCache<'tcx>,
+
+    /// The constants without inference variables which were already proven to be
+    /// evaluatable in some param env, so that proving it again, e.g. while
+    /// backtracking during selection, is free. Forks start with an empty cache.
+    pub const_evaluatable_cache: ConstEvaluatableCache<'tcx>,
+
     /// the set of predicates on which errors have been reported, to
     /// avoid reporting the same error twice.
     pub reported_trait_errors: RefCell<FxIndexMap<Span, Vec<ty::Predicate<'tcx>>>>,
@@ -638,6 +656,8 @@ pub fn build(&mut self) -> InferCtxt<'tcx> {
             lexical_region_resolutions: RefCell::new(None),
             selection_cache: Default::default(),
             evaluation_cache: Default::default(),
+            const_eval_failure_cache: Default::default(),
+            const_evaluatable_cache: Default::default(),
             reported_trait_errors: Default::default(),
             reported_closure_mismatch: Default::default(),
             tainted_by_errors: Cell::new(None),
@@ -714,6 +734,19 @@ pub fn type_var_origin(&self, ty: Ty<'tcx>) -> Option<TypeVariableOrigin> {
         }
     }
 
+    /// Returns the origin of the const variable identified by `ct`, or `None`
+    /// if this is not a const variable.
+    ///
+    /// No attempt is made to resolve `ct`.
+    pub fn const_var_origin(&self, ct: ty::Const<'tcx>) -> Option<ConstVariableOrigin> {
+        match ct.kind() {
+            ty::ConstKind::Infer(InferConst::Var(vid)) => {
+                Some(self.inner.borrow_mut().const_unification_table().probe_value(vid).origin)
+            }
+            _ => None,
+        }
+    }
+
     pub fn freshener<'b>(&'b self) -> TypeFreshener<'b, 'tcx> {
         freshen::TypeFreshener::new(self, false)
     }
@@ -0,0 +1,85 @@
+use rustc_hir as hir;
+use rustc_infer::infer::TyCtxtInferExt;
+use rustc_macros::LintDiagnostic;
+use rustc_middle::ty::{self, TypeVisitable};
+use rustc_span::Span;
+use rustc_trait_selection::traits::const_evaluatable::is_const_evaluatable_expanded;
+
+use crate::{LateContext, LateLintPass, LintContext};
+
+declare_lint! {
+    /// The `unnecessary_const_evaluatable_bounds` lint detects `where [(); EXPR]:`
+    /// bounds whose constant expression doesn't depend on any generic parameters.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// #![allow(incomplete_features)]
+    ///
+    /// fn foo<const N: usize>() where [(); 3 + 1]: {}
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// With `#![feature(generic_const_exprs)]`, such bounds are needed to use
+    /// constant expressions which depend on generic parameters in types. A
+    /// constant which doesn't depend on any of them is evaluated where it is
+    /// defined, so the bound is always satisfied and can be removed.
+    pub UNNECESSARY_CONST_EVALUATABLE_BOUNDS,
+    Warn,
+    "detects `where [(); EXPR]:` bounds which don't depend on generic parameters"
+}
+
+declare_lint_pass!(UnnecessaryConstEvaluatableBounds => [UNNECESSARY_CONST_EVALUATABLE_BOUNDS]);
+
+impl<'tcx> LateLintPass<'tcx> for UnnecessaryConstEvaluatableBounds {
+    fn check_generics(&mut self, cx: &LateContext<'tcx>, generics: &'tcx hir::Generics<'tcx>) {
+        if !cx.tcx.features().generic_const_exprs {
+            return;
+        }
+
+        for (pos, predicate) in generics.predicates.iter().enumerate() {
+            let hir::WherePredicate::BoundPredicate(bound) = predicate else { continue };
+            if !bound.bounds.is_empty() {
+                continue;
+            }
+            let hir::TyKind::Array(_, hir::ArrayLen::Body(anon_const)) = bound.bounded_ty.kind
+            else {
+                continue;
+            };
+
+            let ct = ty::Const::from_anon_const(cx.tcx, anon_const.def_id);
+            let ty::ConstKind::Unevaluated(uv) = ct.kind() else { continue };
+            let expanded = cx.tcx.expand_abstract_consts(ct);
+            if expanded.has_non_region_param() {
+                continue;
+            }
+
+            // Evaluate the constant without the where clauses, as it would
+            // otherwise trivially be proven by the bound we're looking at.
+            let infcx = cx.tcx.infer_ctxt().build();
+            let param_env = cx.param_env.without_caller_bounds();
+            if is_const_evaluatable_expanded(&infcx, expanded, uv, param_env, predicate.span())
+                .is_ok()
+            {
+                cx.emit_spanned_lint(
+                    UNNECESSARY_CONST_EVALUATABLE_BOUNDS,
+                    predicate.span(),
+                    UnnecessaryConstEvaluatableBound {
+                        suggestion: generics.span_for_predicate_removal(pos),
+                    },
+                );
+            }
+        }
+    }
+}
+
+#[derive(LintDiagnostic)]
+#[diag(lint_unnecessary_const_evaluatable_bound)]
+struct UnnecessaryConstEvaluatableBound {
+    #[suggestion(style = "verbose", code = "", applicability = "machine-applicable")]
+    suggestion: Span,
+}
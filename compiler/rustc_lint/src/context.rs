@@ -20,9 +20,12 @@
     CheckNameDeprecated, CheckNameUnknown, CheckNameUnknownTool, CheckNameWarning, RequestedLevel,
     UnsupportedGroup,
 };
+use crate::late;
 use crate::levels::LintLevelsBuilder;
 use crate::passes::{EarlyLintPassObject, LateLintPassObject};
+use crate::{BuiltinCombinedLateLintPass, BuiltinCombinedModuleLateLintPass};
 use rustc_ast::util::unicode::TEXT_FLOW_CONTROL_CHARS;
+use rustc_data_structures::captures::Captures;
 use rustc_data_structures::fx::FxHashMap;
 use rustc_data_structures::sync;
 use rustc_errors::{add_elided_lifetime_in_path_suggestion, DiagnosticBuilder, DiagnosticMessage};
@@ -31,6 +34,7 @@
 use rustc_hir::def::Res;
 use rustc_hir::def_id::{CrateNum, DefId};
 use rustc_hir::definitions::{DefPathData, DisambiguatedDefPathData};
+use rustc_middle::lint::LintLevelSource;
 use rustc_middle::middle::privacy::EffectiveVisibilities;
 use rustc_middle::middle::stability;
 use rustc_middle::ty::layout::{LayoutError, LayoutOfHelpers, TyAndLayout};
@@ -44,12 +48,13 @@
 use rustc_span::{BytePos, Span};
 use rustc_target::abi;
 
-use std::cell::Cell;
+use std::any::{Any, TypeId};
+use std::cell::{Cell, RefCell};
 use std::iter;
 use std::slice;
 
 type EarlyLintPassFactory = dyn Fn() -> EarlyLintPassObject + sync::Send + sync::Sync;
-type LateLintPassFactory =
+pub(crate) type LateLintPassFactory =
     dyn for<'tcx> Fn(TyCtxt<'tcx>) -> LateLintPassObject<'tcx> + sync::Send + sync::Sync;
 
 /// Information about the registered lints.
@@ -72,6 +77,10 @@ pub struct LintStore {
     /// This is unique in that we construct them per-module, so not once.
     pub late_module_passes: Vec<Box<LateLintPassFactory>>,
 
+    /// The state the module passes accumulate across modules, by type, to be flushed
+    /// in `LateLintPass::finalize`: see `LateContext::with_module_pass_state`.
+    pub(crate) module_pass_state: sync::Lock<FxHashMap<TypeId, Box<dyn Any + sync::Send>>>,
+
     /// Lints indexed by name.
     by_name: FxHashMap<String, TargetLint>,
 
@@ -141,6 +150,7 @@ pub fn new() -> LintStore {
             early_passes: vec![],
             late_passes: vec![],
             late_module_passes: vec![],
+            module_pass_state: Default::default(),
             by_name: Default::default(),
             lint_groups: Default::default(),
         }
@@ -206,6 +216,21 @@ pub fn register_late_mod_pass(
         self.late_module_passes.push(Box::new(pass));
     }
 
+    /// The names of the late lint passes, crate passes first, then module passes.
+    /// The built-in passes, which aren't registered here, come before the
+    /// registered ones of each kind.
+    pub fn late_pass_names<'a, 'tcx: 'a>(
+        &'a self,
+        tcx: TyCtxt<'tcx>,
+    ) -> impl Iterator<Item = &'static str> + Captures<'tcx> + 'a {
+        BuiltinCombinedLateLintPass::PASS_NAMES
+            .iter()
+            .copied()
+            .chain(late::late_pass_names(tcx, &self.late_passes))
+            .chain(BuiltinCombinedModuleLateLintPass::PASS_NAMES.iter().copied())
+            .chain(late::late_pass_names(tcx, &self.late_module_passes))
+    }
+
     /// Helper method for register_early/late_pass
     pub fn register_lints(&mut self, lints: &[&'static Lint]) {
         for lint in lints {
@@ -532,12 +557,25 @@ pub struct LateContext<'tcx> {
     /// Current body, or `None` if outside a body.
     pub enclosing_body: Option<hir::BodyId>,
 
+    /// Bodies the current node is nested in, innermost last. Access using the
+    /// `enclosing_body_stack` method.
+    pub(super) enclosing_body_stack: Vec<hir::BodyId>,
+
+    /// Expressions the current node is nested in, innermost last, within the
+    /// current item. Access using the `parent_expr` method.
+    pub(super) parent_exprs: Vec<&'tcx hir::Expr<'tcx>>,
+
     /// Type-checking results for the current body. Access using the `typeck_results`
     /// and `maybe_typeck_results` methods, which handle querying the typeck results on demand.
     // FIXME(eddyb) move all the code accessing internal fields like this,
     // to this module, to avoid exposing it to lint logic.
     pub(super) cached_typeck_results: Cell<Option<&'tcx ty::TypeckResults<'tcx>>>,
 
+    /// Type-checking results for the last body other than the current one passed
+    /// to `typeck_results_for`, e.g. the body of the function a closure is in.
+    pub(super) cached_other_typeck_results:
+        Cell<Option<(hir::BodyId, &'tcx ty::TypeckResults<'tcx>)>>,
+
     /// Parameter environment for the item we are in.
     pub param_env: ty::ParamEnv<'tcx>,
 
@@ -554,6 +592,11 @@ pub struct LateContext<'tcx> {
 
     /// We are only looking at one module
     pub only_module: bool,
+
+    /// Values passes shared about the expressions being visited, keyed by the
+    /// expression, then by their type. Access using the `share_value` and
+    /// `shared_value` methods.
+    pub(super) shared_values: RefCell<FxHashMap<hir::HirId, FxHashMap<TypeId, Box<dyn Any>>>>,
 }
 
 /// Context for lint checking of the AST, after expansion, before lowering to HIR.
@@ -1076,6 +1119,91 @@ pub fn typeck_results(&self) -> &'tcx ty::TypeckResults<'tcx> {
         self.maybe_typeck_results().expect("`LateContext::typeck_results` called outside of body")
     }
 
+    /// Gets the type-checking results for an arbitrary body.
+    /// When `body_id` is the current body, this goes through the same cache
+    /// as `typeck_results`; otherwise the results are queried without
+    /// disturbing the cache of the body currently being visited, and are
+    /// cached until another body is asked for.
+    pub fn typeck_results_for(&self, body_id: hir::BodyId) -> &'tcx ty::TypeckResults<'tcx> {
+        if self.enclosing_body == Some(body_id) {
+            return self.typeck_results();
+        }
+        if let Some((cached_body_id, typeck_results)) = self.cached_other_typeck_results.get()
+            && cached_body_id == body_id
+        {
+            return typeck_results;
+        }
+        let typeck_results = self.tcx.typeck_body(body_id);
+        self.cached_other_typeck_results.set(Some((body_id, typeck_results)));
+        typeck_results
+    }
+
+    /// Returns the bodies the current node is nested in, innermost last.
+    /// The last element, if any, is `enclosing_body`. Inside a closure, it is
+    /// preceded by the body of the function (or closure) containing it.
+    pub fn enclosing_body_stack(&self) -> &[hir::BodyId] {
+        &self.enclosing_body_stack
+    }
+
+    /// Runs `f` on the state of type `S` the module passes accumulate across modules,
+    /// which starts as `S::default()`. The instances of a module pass checking
+    /// different modules, possibly in parallel, and the one `finalize` is called on
+    /// all see the same state, so this is where the data to be flushed in `finalize`
+    /// goes. The state is dropped after `finalize`.
+    pub fn with_module_pass_state<S: Any + sync::Send + Default, R>(
+        &self,
+        f: impl FnOnce(&mut S) -> R,
+    ) -> R {
+        let mut module_pass_state = self.lint_store.module_pass_state.lock();
+        let state: &mut dyn Any = &mut **module_pass_state
+            .entry(TypeId::of::<S>())
+            .or_insert_with(|| Box::new(S::default()));
+        f(state.downcast_mut::<S>().unwrap())
+    }
+
+    /// Shares `value` about the expression `hir_id` with the other late lint passes,
+    /// so passes which need the same data derived from a node can compute it only
+    /// once. Values shared in `check_expr` can be read by the passes which run after
+    /// the current one, or by any pass in `check_expr_post`. Sharing another value
+    /// of the same type replaces it. Values are forgotten once the visitor is done
+    /// with the expression, after `check_expr_post`.
+    pub fn share_value<T: Any>(&self, hir_id: hir::HirId, value: T) {
+        let mut shared_values = self.shared_values.borrow_mut();
+        shared_values.entry(hir_id).or_default().insert(TypeId::of::<T>(), Box::new(value));
+    }
+
+    /// Returns the value of type `T` a pass shared about the expression `hir_id`
+    /// with `share_value`, if any.
+    pub fn shared_value<T: Any + Clone>(&self, hir_id: hir::HirId) -> Option<T> {
+        let shared_values = self.shared_values.borrow();
+        shared_values.get(&hir_id)?.get(&TypeId::of::<T>())?.downcast_ref::<T>().cloned()
+    }
+
+    /// Forgets the values shared about the expression `hir_id`. This runs for every
+    /// expression, so it is a single removal from the table.
+    pub(super) fn forget_shared_values(&self, hir_id: hir::HirId) {
+        self.shared_values.borrow_mut().remove(&hir_id);
+    }
+
+    /// Returns the innermost expression the current node is nested in, e.g. the
+    /// method call `a.b()` while checking its receiver `a`, or `None` if there
+    /// is none. Unlike the HIR parent, this skips the nodes which aren't
+    /// expressions, such as statements: the parent expression of the initializer
+    /// of `let _ = a;` is the block of the `let` statement. It never looks past
+    /// the item being visited, but goes on past the bodies of closures and inline
+    /// consts.
+    pub fn parent_expr(&self) -> Option<&'tcx hir::Expr<'tcx>> {
+        self.parent_exprs.last().copied()
+    }
+
+    /// Returns the level of `lint` at the node currently being visited, along with
+    /// where that level was set. Unlike `LintContext::get_lint_level`, this is
+    /// meant for checking lints other than the one being emitted, e.g. to decide
+    /// whether a related diagnostic is worth emitting.
+    pub fn lint_level_at_node(&self, lint: &'static Lint) -> (Level, LintLevelSource) {
+        self.tcx.lint_level_at_node(lint, self.last_node_with_lint_attrs)
+    }
+
     /// Returns the final resolution of a `QPath`, or `Res::Err` if unavailable.
     /// Unlike `.typeck_results().qpath_res(qpath, id)`, this can be used even outside
     /// bodies (e.g. for paths in `hir::Ty`), without any risk of ICE-ing.
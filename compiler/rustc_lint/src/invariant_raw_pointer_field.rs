@@ -0,0 +1,85 @@
+use rustc_hir as hir;
+use rustc_macros::LintDiagnostic;
+use rustc_session::lint::Level;
+use rustc_span::symbol::Symbol;
+
+use crate::{LateContext, LateLintPass, LintContext};
+
+declare_lint! {
+    /// The `invariant_raw_pointer_field` lint detects type and lifetime
+    /// parameters which are only invariant because of a `*mut` raw pointer
+    /// in a field.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,compile_fail
+    /// #![deny(invariant_raw_pointer_field)]
+    ///
+    /// pub struct Handle<T> {
+    ///     ptr: *mut T,
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Unlike `*const T` and `NonNull<T>`, `*mut T` is invariant in `T`, and so
+    /// is a type with such a field. This prevents using e.g. a `Handle<&'static str>`
+    /// where a `Handle<&'a str>` is expected, which is often unintended. If
+    /// the type isn't meant to be invariant, use `*const T` or `NonNull<T>`
+    /// instead, and cast the pointer to `*mut T` where it is written to.
+    ///
+    /// This lint is "allow" by default, because invariance is the right
+    /// choice for many types with such pointers, and it doesn't fire for
+    /// private fields of types which aren't exported from the crate.
+    pub INVARIANT_RAW_POINTER_FIELD,
+    Allow,
+    "detects generic parameters which are only invariant because of `*mut` fields"
+}
+
+declare_lint_pass!(InvariantRawPointerField => [INVARIANT_RAW_POINTER_FIELD]);
+
+impl<'tcx> LateLintPass<'tcx> for InvariantRawPointerField {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        let (hir::ItemKind::Struct(_, generics)
+        | hir::ItemKind::Enum(_, generics)
+        | hir::ItemKind::Union(_, generics)) = item.kind
+        else {
+            return;
+        };
+        if generics.params.is_empty() {
+            return;
+        }
+        // The lint is allowed by default, so don't solve the variances of every
+        // type for nothing.
+        if cx.tcx.lint_level_at_node(INVARIANT_RAW_POINTER_FIELD, item.hir_id()).0 == Level::Allow {
+            return;
+        }
+
+        let def_id = item.owner_id.def_id;
+        let is_exported = cx.effective_visibilities.is_exported(def_id);
+        let ty_generics = cx.tcx.generics_of(def_id);
+        for &(index, field) in cx.tcx.raw_pointer_invariant_params(def_id) {
+            if !is_exported && !cx.tcx.visibility(field).is_public() {
+                continue;
+            }
+            cx.emit_spanned_lint(
+                INVARIANT_RAW_POINTER_FIELD,
+                cx.tcx.def_span(field),
+                InvariantRawPointerFieldDiag {
+                    param: ty_generics.param_at(index as usize, cx.tcx).name,
+                    ty: item.ident.name,
+                },
+            );
+        }
+    }
+}
+
+#[derive(LintDiagnostic)]
+#[diag(lint_invariant_raw_pointer_field)]
+#[note]
+struct InvariantRawPointerFieldDiag {
+    param: Symbol,
+    ty: Symbol,
+}
@@ -14,14 +14,19 @@
 //! upon. As the ast is traversed, this keeps track of the current lint level
 //! for all lint attributes.
 
+use crate::context::LateLintPassFactory;
 use crate::{passes::LateLintPassObject, LateContext, LateLintPass, LintStore};
 use rustc_ast as ast;
+use rustc_data_structures::captures::Captures;
 use rustc_data_structures::sync::join;
+use rustc_errors::Diagnostic;
 use rustc_hir as hir;
-use rustc_hir::def_id::LocalDefId;
+use rustc_hir::def_id::{DefId, LocalDefId};
 use rustc_hir::intravisit as hir_visit;
 use rustc_hir::intravisit::Visitor;
 use rustc_middle::hir::nested_filter;
+use rustc_middle::lint::with_buffered_lints;
+use rustc_middle::middle::resolve_lifetime as rl;
 use rustc_middle::ty::{self, TyCtxt};
 use rustc_session::lint::LintPass;
 use rustc_span::Span;
@@ -79,6 +84,13 @@ fn process_mod(&mut self, m: &'tcx hir::Mod<'tcx>, n: hir::HirId) {
         lint_callback!(self, check_mod, m, n);
         hir_visit::walk_mod(self, m, n);
     }
+
+    fn visit_attributes(&mut self, attrs: &'tcx [ast::Attribute], id: hir::HirId) {
+        lint_callback!(self, check_attributes, attrs, id);
+        for attr in attrs {
+            self.visit_attribute(attr);
+        }
+    }
 }
 
 impl<'tcx, T: LateLintPass<'tcx>> hir_visit::Visitor<'tcx> for LateContextAndPass<'tcx, T> {
@@ -100,6 +112,7 @@ fn visit_nested_body(&mut self, body_id: hir::BodyId) {
         // being queried.
         if old_enclosing_body != Some(body_id) {
             self.context.cached_typeck_results.set(None);
+            self.context.enclosing_body_stack.push(body_id);
         }
 
         let body = self.context.tcx.hir().body(body_id);
@@ -109,6 +122,7 @@ fn visit_nested_body(&mut self, body_id: hir::BodyId) {
         // See HACK comment above.
         if old_enclosing_body != Some(body_id) {
             self.context.cached_typeck_results.set(old_cached_typeck_results);
+            self.context.enclosing_body_stack.pop();
         }
     }
 
@@ -129,14 +143,27 @@ fn visit_item(&mut self, it: &'tcx hir::Item<'tcx>) {
         self.context.generics = it.kind.generics();
         let old_cached_typeck_results = self.context.cached_typeck_results.take();
         let old_enclosing_body = self.context.enclosing_body.take();
+        let old_enclosing_body_stack = std::mem::take(&mut self.context.enclosing_body_stack);
+        let old_parent_exprs = std::mem::take(&mut self.context.parent_exprs);
         self.with_lint_attrs(it.hir_id(), |cx| {
             cx.with_param_env(it.hir_id(), |cx| {
                 lint_callback!(cx, check_item, it);
+                match it.kind {
+                    hir::ItemKind::OpaqueTy(ref opaque) => {
+                        lint_callback!(cx, check_opaque_ty, opaque, it.owner_id.def_id);
+                    }
+                    hir::ItemKind::Use(path, kind) => {
+                        lint_callback!(cx, check_use, path, kind);
+                    }
+                    _ => {}
+                }
                 hir_visit::walk_item(cx, it);
                 lint_callback!(cx, check_item_post, it);
             });
         });
         self.context.enclosing_body = old_enclosing_body;
+        self.context.enclosing_body_stack = old_enclosing_body_stack;
+        self.context.parent_exprs = old_parent_exprs;
         self.context.cached_typeck_results.set(old_cached_typeck_results);
         self.context.generics = generics;
     }
@@ -146,6 +173,7 @@ fn visit_foreign_item(&mut self, it: &'tcx hir::ForeignItem<'tcx>) {
             cx.with_param_env(it.hir_id(), |cx| {
                 lint_callback!(cx, check_foreign_item, it);
                 hir_visit::walk_foreign_item(cx, it);
+                lint_callback!(cx, check_foreign_item_post, it);
             });
         })
     }
@@ -155,14 +183,56 @@ fn visit_pat(&mut self, p: &'tcx hir::Pat<'tcx>) {
         hir_visit::walk_pat(self, p);
     }
 
+    fn visit_pat_field(&mut self, field: &'tcx hir::PatField<'tcx>) {
+        lint_callback!(self, check_pat_field, field);
+        hir_visit::walk_pat_field(self, field);
+    }
+
     fn visit_expr(&mut self, e: &'tcx hir::Expr<'tcx>) {
+        // Expressions only occur in bodies, including the initializers of `const`s
+        // and `static`s and anon consts, all of which set the enclosing body, so
+        // `check_expr` and `check_expr_post` can rely on `typeck_results`.
+        debug_assert!(self.context.enclosing_body.is_some(), "expression outside of a body");
         self.with_lint_attrs(e.hir_id, |cx| {
             lint_callback!(cx, check_expr, e);
+            match e.kind {
+                hir::ExprKind::Closure(closure) => {
+                    lint_callback!(cx, check_closure, closure, e.hir_id);
+                }
+                hir::ExprKind::Field(base, ident) => {
+                    lint_callback!(cx, check_field_expr, e, base, ident);
+                }
+                hir::ExprKind::ConstBlock(ref anon_const) => {
+                    lint_callback!(cx, check_inline_const, anon_const);
+                }
+                _ => {}
+            }
+            cx.context.parent_exprs.push(e);
             hir_visit::walk_expr(cx, e);
+            cx.context.parent_exprs.pop();
             lint_callback!(cx, check_expr_post, e);
+            cx.context.forget_shared_values(e.hir_id);
         })
     }
 
+    fn visit_anon_const(&mut self, c: &'tcx hir::AnonConst) {
+        // Wrap in typeck results here, not just in visit_nested_body,
+        // in order for `check_anon_const` to be able to use them.
+        let old_enclosing_body = self.context.enclosing_body.replace(c.body);
+        let old_cached_typeck_results = self.context.cached_typeck_results.take();
+        self.context.enclosing_body_stack.push(c.body);
+        lint_callback!(self, check_anon_const, c);
+        hir_visit::walk_anon_const(self, c);
+        self.context.enclosing_body_stack.pop();
+        self.context.enclosing_body = old_enclosing_body;
+        self.context.cached_typeck_results.set(old_cached_typeck_results);
+    }
+
+    fn visit_let_expr(&mut self, lex: &'tcx hir::Let<'tcx>) {
+        lint_callback!(self, check_let_expr, lex);
+        hir_visit::walk_let_expr(self, lex);
+    }
+
     fn visit_stmt(&mut self, s: &'tcx hir::Stmt<'tcx>) {
         // See `EarlyContextAndPass::visit_stmt` for an explanation
         // of why we call `walk_stmt` outside of `with_lint_attrs`
@@ -170,6 +240,9 @@ fn visit_stmt(&mut self, s: &'tcx hir::Stmt<'tcx>) {
             lint_callback!(cx, check_stmt, s);
         });
         hir_visit::walk_stmt(self, s);
+        self.with_lint_attrs(s.hir_id, |cx| {
+            lint_callback!(cx, check_stmt_post, s);
+        });
     }
 
     fn visit_fn(
@@ -184,9 +257,11 @@ fn visit_fn(
         // in order for `check_fn` to be able to use them.
         let old_enclosing_body = self.context.enclosing_body.replace(body_id);
         let old_cached_typeck_results = self.context.cached_typeck_results.take();
+        self.context.enclosing_body_stack.push(body_id);
         let body = self.context.tcx.hir().body(body_id);
         lint_callback!(self, check_fn, fk, decl, body, span, id);
         hir_visit::walk_fn(self, fk, decl, body_id, id);
+        self.context.enclosing_body_stack.pop();
         self.context.enclosing_body = old_enclosing_body;
         self.context.cached_typeck_results.set(old_cached_typeck_results);
     }
@@ -212,6 +287,19 @@ fn visit_variant(&mut self, v: &'tcx hir::Variant<'tcx>) {
 
     fn visit_ty(&mut self, t: &'tcx hir::Ty<'tcx>) {
         lint_callback!(self, check_ty, t);
+        if let hir::TyKind::OpaqueDef(_, captures, _) = t.kind {
+            // The captures only list lifetimes: type and const parameters are
+            // captured implicitly.
+            for arg in captures {
+                let hir::GenericArg::Lifetime(lifetime) = arg else { continue };
+                match self.context.tcx.named_region(lifetime.hir_id) {
+                    Some(rl::Region::EarlyBound(def_id) | rl::Region::LateBound(_, _, def_id)) => {
+                        lint_callback!(self, check_opaque_captured_arg, arg, def_id);
+                    }
+                    _ => {}
+                }
+            }
+        }
         hir_visit::walk_ty(self, t);
     }
 
@@ -241,6 +329,12 @@ fn visit_block(&mut self, b: &'tcx hir::Block<'tcx>) {
     fn visit_arm(&mut self, a: &'tcx hir::Arm<'tcx>) {
         lint_callback!(self, check_arm, a);
         hir_visit::walk_arm(self, a);
+        lint_callback!(self, check_arm_post, a);
+    }
+
+    fn visit_generic_arg(&mut self, arg: &'tcx hir::GenericArg<'tcx>) {
+        lint_callback!(self, check_generic_arg, arg);
+        hir_visit::walk_generic_arg(self, arg);
     }
 
     fn visit_generic_param(&mut self, p: &'tcx hir::GenericParam<'tcx>) {
@@ -254,6 +348,7 @@ fn visit_generics(&mut self, g: &'tcx hir::Generics<'tcx>) {
     }
 
     fn visit_where_predicate(&mut self, p: &'tcx hir::WherePredicate<'tcx>) {
+        lint_callback!(self, check_where_predicate, p);
         hir_visit::walk_where_predicate(self, p);
     }
 
@@ -269,6 +364,7 @@ fn visit_trait_item(&mut self, trait_item: &'tcx hir::TraitItem<'tcx>) {
             cx.with_param_env(trait_item.hir_id(), |cx| {
                 lint_callback!(cx, check_trait_item, trait_item);
                 hir_visit::walk_trait_item(cx, trait_item);
+                lint_callback!(cx, check_trait_item_post, trait_item);
             });
         });
         self.context.generics = generics;
@@ -288,12 +384,31 @@ fn visit_impl_item(&mut self, impl_item: &'tcx hir::ImplItem<'tcx>) {
     }
 
     fn visit_lifetime(&mut self, lt: &'tcx hir::Lifetime) {
+        lint_callback!(self, check_lifetime, lt);
         hir_visit::walk_lifetime(self, lt);
     }
 
+    fn visit_qpath(&mut self, qpath: &'tcx hir::QPath<'tcx>, id: hir::HirId, span: Span) {
+        lint_callback!(self, check_qpath, qpath, id, span);
+        hir_visit::walk_qpath(self, qpath, id);
+    }
+
     fn visit_path(&mut self, p: &hir::Path<'tcx>, id: hir::HirId) {
         lint_callback!(self, check_path, p, id);
         hir_visit::walk_path(self, p);
+        lint_callback!(self, check_path_post, p, id);
+    }
+
+    fn visit_path_segment(&mut self, s: &'tcx hir::PathSegment<'tcx>) {
+        if let Some(args) = s.args {
+            lint_callback!(self, check_generic_args, args, s.hir_id);
+        }
+        hir_visit::walk_path_segment(self, s);
+    }
+
+    fn visit_inline_asm(&mut self, asm: &'tcx hir::InlineAsm<'tcx>, id: hir::HirId) {
+        lint_callback!(self, check_inline_asm, asm);
+        hir_visit::walk_inline_asm(self, asm, id);
     }
 
     fn visit_attribute(&mut self, attr: &'tcx ast::Attribute) {
@@ -305,18 +420,78 @@ struct LateLintPassObjects<'a, 'tcx> {
     lints: &'a mut [LateLintPassObject<'tcx>],
 }
 
+/// Instantiates each of the given passes, only to ask for its name. The passes
+/// themselves aren't run.
+pub(crate) fn late_pass_names<'a, 'tcx: 'a>(
+    tcx: TyCtxt<'tcx>,
+    passes: &'a [Box<LateLintPassFactory>],
+) -> impl Iterator<Item = &'static str> + Captures<'tcx> + 'a {
+    passes.iter().map(move |pass| (pass)(tcx).name())
+}
+
 #[allow(rustc::lint_pass_impl_without_macro)]
 impl LintPass for LateLintPassObjects<'_, '_> {
+    /// A synthetic name for the aggregate; use `late_pass_names` for the names
+    /// of the passes it contains.
     fn name(&self) -> &'static str {
-        panic!()
+        "LateLintPassObjects"
     }
 }
 
+/// The definition an item or fn callback is invoked for, if any. Used to skip
+/// passes whose `applicable_def_kinds` don't include the kind of that definition.
+macro_rules! callback_def_id {
+    (check_item, $cx:expr, $it:expr) => {
+        Some($it.owner_id.to_def_id())
+    };
+    (check_item_post, $cx:expr, $it:expr) => {
+        Some($it.owner_id.to_def_id())
+    };
+    (check_foreign_item, $cx:expr, $it:expr) => {
+        Some($it.owner_id.to_def_id())
+    };
+    (check_foreign_item_post, $cx:expr, $it:expr) => {
+        Some($it.owner_id.to_def_id())
+    };
+    (check_trait_item, $cx:expr, $it:expr) => {
+        Some($it.owner_id.to_def_id())
+    };
+    (check_trait_item_post, $cx:expr, $it:expr) => {
+        Some($it.owner_id.to_def_id())
+    };
+    (check_opaque_ty, $cx:expr, $opaque:expr, $def_id:expr) => {
+        Some($def_id.to_def_id())
+    };
+    (check_impl_item, $cx:expr, $it:expr) => {
+        Some($it.owner_id.to_def_id())
+    };
+    (check_impl_item_post, $cx:expr, $it:expr) => {
+        Some($it.owner_id.to_def_id())
+    };
+    (check_fn, $cx:expr, $fk:expr, $decl:expr, $body:expr, $span:expr, $id:expr) => {
+        $cx.tcx.hir().opt_local_def_id($id).map(LocalDefId::to_def_id)
+    };
+    ($name:ident, $cx:expr $(, $args:expr)*) => {
+        None::<DefId>
+    };
+}
+
 macro_rules! late_lint_pass_impl {
     ([], [$hir:tt], [$($(#[$attr:meta])* fn $name:ident($($param:ident: $arg:ty),*);)*]) => {
         impl<$hir> LateLintPass<$hir> for LateLintPassObjects<'_, $hir> {
             $(fn $name(&mut self, context: &LateContext<$hir>, $($param: $arg),*) {
+                // The kind of the definition is only looked up once some pass needs it.
+                let mut def_kind = None;
                 for obj in self.lints.iter_mut() {
+                    if let Some(def_kinds) = obj.applicable_def_kinds()
+                        && let Some(def_kind) = *def_kind.get_or_insert_with(|| {
+                            callback_def_id!($name, context $(, $param)*)
+                                .map(|def_id| context.tcx.def_kind(def_id))
+                        })
+                        && !def_kinds.contains(&def_kind)
+                    {
+                        continue;
+                    }
                     obj.$name(context, $($param),*);
                 }
             })*
@@ -334,13 +509,17 @@ pub(super) fn late_lint_mod<'tcx, T: LateLintPass<'tcx> + 'tcx>(
     let context = LateContext {
         tcx,
         enclosing_body: None,
+        enclosing_body_stack: Vec::new(),
+        parent_exprs: Vec::new(),
         cached_typeck_results: Cell::new(None),
+        cached_other_typeck_results: Cell::new(None),
         param_env: ty::ParamEnv::empty(),
         effective_visibilities: &tcx.effective_visibilities(()),
         lint_store: unerased_lint_store(tcx),
         last_node_with_lint_attrs: tcx.hir().local_def_id_to_hir_id(module_def_id),
         generics: None,
         only_module: true,
+        shared_values: Default::default(),
     };
 
     let mut passes: Vec<_> =
@@ -355,23 +534,94 @@ pub(super) fn late_lint_mod<'tcx, T: LateLintPass<'tcx> + 'tcx>(
 
     // Visit the crate attributes
     if hir_id == hir::CRATE_HIR_ID {
-        for attr in tcx.hir().attrs(hir::CRATE_HIR_ID).iter() {
-            cx.visit_attribute(attr)
-        }
+        cx.visit_attributes(tcx.hir().attrs(hir::CRATE_HIR_ID), hir::CRATE_HIR_ID);
+    }
+}
+
+/// Runs the module lint passes over each of `module_def_ids`, in that order and on
+/// the current thread, with a fresh `builtin_lints()` for each module. The lints
+/// emitted are the same as those `check_crate` emits for these modules, but the
+/// whole-crate passes and `LateLintPass::finalize` are not run. This is meant for
+/// tools which only want to check a few modules, e.g. those which were changed.
+pub fn late_lint_modules<'tcx, T: LateLintPass<'tcx> + 'tcx>(
+    tcx: TyCtxt<'tcx>,
+    module_def_ids: &[LocalDefId],
+    mut builtin_lints: impl FnMut() -> T,
+) {
+    for &module_def_id in module_def_ids {
+        late_lint_mod(tcx, module_def_id, builtin_lints());
+    }
+}
+
+/// Runs a single late lint pass over the item `item_def_id`, without walking
+/// the rest of the crate, and returns the pass afterwards.
+///
+/// Like for `late_lint_mod`, items nested in `item_def_id` (such as fns defined
+/// inside a fn body) are visited as well, so that lint levels set on the outer
+/// item apply to them. Nested modules are not descended into.
+pub fn run_late_pass_on_item<'tcx, T: LateLintPass<'tcx> + 'tcx>(
+    tcx: TyCtxt<'tcx>,
+    item_def_id: LocalDefId,
+    pass: T,
+) -> T {
+    let context = LateContext {
+        tcx,
+        enclosing_body: None,
+        enclosing_body_stack: Vec::new(),
+        parent_exprs: Vec::new(),
+        cached_typeck_results: Cell::new(None),
+        cached_other_typeck_results: Cell::new(None),
+        param_env: tcx.param_env(item_def_id),
+        effective_visibilities: &tcx.effective_visibilities(()),
+        lint_store: unerased_lint_store(tcx),
+        last_node_with_lint_attrs: tcx.hir().local_def_id_to_hir_id(item_def_id),
+        generics: None,
+        only_module: true,
+        shared_values: Default::default(),
+    };
+
+    let mut cx = LateContextAndPass { context, pass };
+
+    match tcx.hir().expect_owner(item_def_id) {
+        hir::OwnerNode::Item(item) => cx.visit_item(item),
+        hir::OwnerNode::ForeignItem(item) => cx.visit_foreign_item(item),
+        hir::OwnerNode::TraitItem(item) => cx.visit_trait_item(item),
+        hir::OwnerNode::ImplItem(item) => cx.visit_impl_item(item),
+        hir::OwnerNode::Crate(module) => cx.process_mod(module, hir::CRATE_HIR_ID),
     }
+
+    cx.pass
+}
+
+/// Like `run_late_pass_on_item`, but returns the lints emitted by `pass` instead of
+/// emitting them, e.g. to preview them in an IDE. Lints which are allowed aren't
+/// returned. The diagnostics of the queries `pass` executes, such as the errors and
+/// lints from type-checking the item, are not returned but emitted as usual, and the
+/// returned lints are not recorded as side effects of the current query: see
+/// `with_buffered_lints`.
+pub fn collect_late_lints_on_item<'tcx, T: LateLintPass<'tcx> + 'tcx>(
+    tcx: TyCtxt<'tcx>,
+    item_def_id: LocalDefId,
+    pass: T,
+) -> (T, Vec<Diagnostic>) {
+    with_buffered_lints(|| run_late_pass_on_item(tcx, item_def_id, pass))
 }
 
 fn late_lint_crate<'tcx, T: LateLintPass<'tcx> + 'tcx>(tcx: TyCtxt<'tcx>, builtin_lints: T) {
     let context = LateContext {
         tcx,
         enclosing_body: None,
+        enclosing_body_stack: Vec::new(),
+        parent_exprs: Vec::new(),
         cached_typeck_results: Cell::new(None),
+        cached_other_typeck_results: Cell::new(None),
         param_env: ty::ParamEnv::empty(),
         effective_visibilities: &tcx.effective_visibilities(()),
         lint_store: unerased_lint_store(tcx),
         last_node_with_lint_attrs: hir::CRATE_HIR_ID,
         generics: None,
         only_module: false,
+        shared_values: Default::default(),
     };
 
     let mut passes =
@@ -387,28 +637,130 @@ fn late_lint_crate<'tcx, T: LateLintPass<'tcx> + 'tcx>(tcx: TyCtxt<'tcx>, builti
         // item), warn for it here.
         lint_callback!(cx, check_crate,);
         tcx.hir().walk_toplevel_module(cx);
-        tcx.hir().walk_attributes(cx);
+        for (def_id, info) in tcx.hir().krate().owners.iter_enumerated() {
+            if let hir::MaybeOwner::Owner(info) = info {
+                let owner = hir::OwnerId { def_id };
+                for &(local_id, attrs) in info.attrs.map.iter() {
+                    cx.visit_attributes(attrs, hir::HirId { owner, local_id });
+                }
+            }
+        }
         lint_callback!(cx, check_crate_post,);
     })
 }
 
+/// Runs `f`, returning the lints the current query job emits meanwhile, errors
+/// included, so that the caller can emit them in a deterministic order with
+/// `emit_lints`. The errors are only counted once they are emitted.
+pub(crate) fn buffer_lints(f: impl FnOnce()) -> Vec<Diagnostic> {
+    let ((), lints) = with_buffered_lints(f);
+    lints
+}
+
+fn emit_lints(tcx: TyCtxt<'_>, lints: &[Diagnostic]) {
+    let handler = tcx.sess.diagnostic();
+    for diag in lints {
+        handler.emit_diagnostic(&mut diag.clone());
+    }
+}
+
 /// Performs lint checking on a crate.
+///
+/// The passes may run in any order, so their lints are emitted once all of them are
+/// done, in a deterministic order: whole-crate lints first, then module lints by module.
+/// This includes the lints which are errors, which nothing needs to count before.
+/// With `-Z sequential-late-lints`, the passes run one after the other on the current
+/// thread, which makes it easier to reproduce lint bugs that depend on the order they
+/// run in. The lints emitted, and their order, are the same.
 pub fn check_crate<'tcx, T: LateLintPass<'tcx> + 'tcx>(
     tcx: TyCtxt<'tcx>,
     builtin_lints: impl FnOnce() -> T + Send,
 ) {
-    join(
+    let crate_lints = if tcx.sess.opts.unstable_opts.sequential_late_lints {
+        check_crate_sequentially(tcx, builtin_lints)
+    } else {
+        check_crate_in_parallel(tcx, builtin_lints)
+    };
+
+    emit_lints(tcx, &crate_lints);
+    let mut modules = Vec::new();
+    tcx.hir().for_each_module(|module| modules.push(module));
+    modules.sort_by_key(|module| module.local_def_index);
+    for module in modules {
+        // The module passes have already run, this only gets the lints they returned.
+        emit_lints(tcx, tcx.lint_mod(module));
+    }
+
+    tcx.sess.time("finalize_module_lints", || finalize_module_passes(tcx));
+}
+
+/// Runs the whole-crate passes and the module passes in parallel, and returns the
+/// lints of the whole-crate passes.
+fn check_crate_in_parallel<'tcx, T: LateLintPass<'tcx> + 'tcx>(
+    tcx: TyCtxt<'tcx>,
+    builtin_lints: impl FnOnce() -> T + Send,
+) -> Vec<Diagnostic> {
+    let (crate_lints, ()) = join(
         || {
             tcx.sess.time("crate_lints", || {
                 // Run whole crate non-incremental lints
-                late_lint_crate(tcx, builtin_lints());
-            });
+                buffer_lints(|| late_lint_crate(tcx, builtin_lints()))
+            })
         },
         || {
             tcx.sess.time("module_lints", || {
                 // Run per-module lints
                 tcx.hir().par_for_each_module(|module| tcx.ensure().lint_mod(module));
-            });
+            })
         },
     );
+    crate_lints
+}
+
+/// Like `check_crate_in_parallel`, but runs the whole-crate passes and then the
+/// module passes, module by module in `DefId` order.
+fn check_crate_sequentially<'tcx, T: LateLintPass<'tcx> + 'tcx>(
+    tcx: TyCtxt<'tcx>,
+    builtin_lints: impl FnOnce() -> T,
+) -> Vec<Diagnostic> {
+    let crate_lints =
+        tcx.sess.time("crate_lints", || buffer_lints(|| late_lint_crate(tcx, builtin_lints())));
+    tcx.sess.time("module_lints", || {
+        let mut modules = Vec::new();
+        tcx.hir().for_each_module(|module| modules.push(module));
+        modules.sort_by_key(|module| module.local_def_index);
+        for module in modules {
+            tcx.ensure().lint_mod(module);
+        }
+    });
+    crate_lints
+}
+
+/// Gives the module passes a chance to flush the state they accumulated across
+/// modules, once all of them have been checked.
+fn finalize_module_passes(tcx: TyCtxt<'_>) {
+    let context = LateContext {
+        tcx,
+        enclosing_body: None,
+        enclosing_body_stack: Vec::new(),
+        parent_exprs: Vec::new(),
+        cached_typeck_results: Cell::new(None),
+        cached_other_typeck_results: Cell::new(None),
+        param_env: ty::ParamEnv::empty(),
+        effective_visibilities: &tcx.effective_visibilities(()),
+        lint_store: unerased_lint_store(tcx),
+        last_node_with_lint_attrs: hir::CRATE_HIR_ID,
+        generics: None,
+        only_module: true,
+        shared_values: Default::default(),
+    };
+
+    let mut passes: Vec<_> =
+        unerased_lint_store(tcx).late_module_passes.iter().map(|pass| (pass)(tcx)).collect();
+    let mut pass = LateLintPassObjects { lints: &mut passes[..] };
+    pass.finalize(&context);
+    context.lint_store.module_pass_state.lock().clear();
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,26 @@
+use super::LateLintPassObjects;
+use crate::passes::LateLintPassObject;
+use crate::BuiltinCombinedModuleLateLintPass;
+use rustc_session::lint::builtin::HardwiredLints;
+use rustc_session::lint::LintPass;
+
+#[test]
+fn late_lint_pass_objects_name() {
+    let mut passes: Vec<LateLintPassObject<'_>> = vec![Box::new(HardwiredLints)];
+    let objects = LateLintPassObjects { lints: &mut passes[..] };
+    assert_eq!(objects.name(), "LateLintPassObjects");
+    assert_eq!(passes[0].name(), "HardwiredLints");
+}
+
+#[test]
+fn empty_late_lint_pass_objects_name() {
+    let objects = LateLintPassObjects { lints: &mut [] };
+    assert_eq!(objects.name(), "LateLintPassObjects");
+}
+
+#[test]
+fn builtin_combined_pass_names() {
+    let names = BuiltinCombinedModuleLateLintPass::PASS_NAMES;
+    assert!(names.contains(&HardwiredLints.name()));
+    assert!(names.iter().all(|name| !name.is_empty()));
+}
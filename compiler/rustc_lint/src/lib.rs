@@ -48,6 +48,7 @@
 
 mod array_into_iter;
 pub mod builtin;
+mod const_evaluatable_bounds;
 mod context;
 mod deref_into_dyn_supertrait;
 mod early;
@@ -57,6 +58,7 @@
 mod for_loops_over_fallibles;
 pub mod hidden_unicode_codepoints;
 mod internal;
+mod invariant_raw_pointer_field;
 mod late;
 mod let_underscore;
 mod levels;
@@ -76,6 +78,7 @@
 pub use array_into_iter::ARRAY_INTO_ITER;
 
 use rustc_ast as ast;
+use rustc_errors::Diagnostic;
 use rustc_hir as hir;
 use rustc_hir::def_id::LocalDefId;
 use rustc_middle::ty::query::Providers;
@@ -88,11 +91,13 @@
 
 use array_into_iter::ArrayIntoIter;
 use builtin::*;
+use const_evaluatable_bounds::*;
 use deref_into_dyn_supertrait::*;
 use enum_intrinsics_non_enums::EnumIntrinsicsNonEnums;
 use for_loops_over_fallibles::*;
 use hidden_unicode_codepoints::*;
 use internal::*;
+use invariant_raw_pointer_field::*;
 use let_underscore::*;
 use methods::*;
 use non_ascii_idents::*;
@@ -111,7 +116,10 @@
 pub use context::{CheckLintNameResult, FindLintError, LintStore};
 pub use context::{EarlyContext, LateContext, LintContext};
 pub use early::{check_ast_node, EarlyCheckNode};
-pub use late::{check_crate, unerased_lint_store};
+pub use late::{
+    check_crate, collect_late_lints_on_item, late_lint_modules, run_late_pass_on_item,
+    unerased_lint_store,
+};
 pub use passes::{EarlyLintPass, LateLintPass};
 pub use rustc_session::lint::Level::{self, *};
 pub use rustc_session::lint::{BufferedEarlyLint, FutureIncompatibleInfo, Lint, LintId};
@@ -123,8 +131,10 @@ pub fn provide(providers: &mut Providers) {
     *providers = Providers { lint_mod, ..*providers };
 }
 
-fn lint_mod(tcx: TyCtxt<'_>, module_def_id: LocalDefId) {
-    late::late_lint_mod(tcx, module_def_id, BuiltinCombinedModuleLateLintPass::new());
+fn lint_mod(tcx: TyCtxt<'_>, module_def_id: LocalDefId) -> Vec<Diagnostic> {
+    late::buffer_lints(|| {
+        late::late_lint_mod(tcx, module_def_id, BuiltinCombinedModuleLateLintPass::new())
+    })
 }
 
 early_lint_methods!(
@@ -228,6 +238,8 @@ fn lint_mod(tcx: TyCtxt<'_>, module_def_id: LocalDefId) {
             InvalidAtomicOrdering: InvalidAtomicOrdering,
             NamedAsmLabels: NamedAsmLabels,
             OpaqueHiddenInferredBound: OpaqueHiddenInferredBound,
+            UnnecessaryConstEvaluatableBounds: UnnecessaryConstEvaluatableBounds,
+            InvariantRawPointerField: InvariantRawPointerField,
         ]
     ],
     ['tcx]
@@ -2,6 +2,7 @@
 
 use rustc_ast as ast;
 use rustc_hir as hir;
+use rustc_hir::def::DefKind;
 use rustc_session::lint::builtin::HardwiredLints;
 use rustc_session::lint::LintPass;
 use rustc_span::symbol::Ident;
@@ -15,21 +16,106 @@ macro_rules! late_lint_methods {
             fn check_body_post(a: &$hir hir::Body<$hir>);
             fn check_crate();
             fn check_crate_post();
+
+            /// Called once after all modules have been checked, for passes registered
+            /// as module passes, which don't get `check_crate_post`. Module passes are
+            /// instantiated anew for each module, possibly in parallel, so this is
+            /// called on yet another instance: state to be flushed here must be kept
+            /// with `LateContext::with_module_pass_state`. With incremental compilation,
+            /// the modules whose `lint_mod` query is green aren't checked again, so
+            /// they don't contribute to that state.
+            fn finalize();
+
             fn check_mod(a: &$hir hir::Mod<$hir>, b: hir::HirId);
             fn check_foreign_item(a: &$hir hir::ForeignItem<$hir>);
+            fn check_foreign_item_post(a: &$hir hir::ForeignItem<$hir>);
             fn check_item(a: &$hir hir::Item<$hir>);
             fn check_item_post(a: &$hir hir::Item<$hir>);
+
+            /// Called for each `use` item, after `check_item`. The path has the resolutions
+            /// of the imported names, in each namespace they were found in. For
+            /// `use a::{b, c};`, this is called for `a::{..}` with `UseKind::ListStem`, and
+            /// for `a::b` and `a::c` as separate items.
+            fn check_use(a: &$hir hir::UsePath<$hir>, b: hir::UseKind);
+
             fn check_local(a: &$hir hir::Local<$hir>);
             fn check_block(a: &$hir hir::Block<$hir>);
             fn check_block_post(a: &$hir hir::Block<$hir>);
             fn check_stmt(a: &$hir hir::Stmt<$hir>);
+
+            /// Called after the contents of a statement have been checked. Like
+            /// `check_stmt`, this is called with the lint attributes of the statement
+            /// applied, while its contents are walked outside of them, see
+            /// `EarlyContextAndPass::visit_stmt`.
+            fn check_stmt_post(a: &$hir hir::Stmt<$hir>);
+
             fn check_arm(a: &$hir hir::Arm<$hir>);
+
+            /// Called after the pattern, guard and body of a match arm have been checked.
+            fn check_arm_post(a: &$hir hir::Arm<$hir>);
+
             fn check_pat(a: &$hir hir::Pat<$hir>);
+
+            /// Called for each field of a struct pattern, before the pattern of the field
+            /// is checked. This includes tuple structs matched with the `S { 0: x }`
+            /// syntax, but not with `S(x)`, whose fields are only patterns.
+            fn check_pat_field(a: &$hir hir::PatField<$hir>);
+
             fn check_expr(a: &$hir hir::Expr<$hir>);
+
+            /// Called for each `let` expression, i.e. the conditions of `if let` and
+            /// `while let` and the `let`s in `let` chains, after `check_expr` is
+            /// called for the expression itself.
+            fn check_let_expr(a: &$hir hir::Let<$hir>);
+
+            /// Called after the subexpressions of an expression have been checked.
+            /// As for `check_expr`, `LateContext::typeck_results` is always available,
+            /// including for expressions in `const` and `static` initializers.
             fn check_expr_post(a: &$hir hir::Expr<$hir>);
+
+            fn check_closure(a: &$hir hir::Closure<$hir>, b: hir::HirId);
+
+            /// Called for each field access expression `a`, i.e. `b.c`, after `check_expr`.
+            /// `LateContext::typeck_results` gives the type of the base expression `b`.
+            fn check_field_expr(
+                a: &$hir hir::Expr<$hir>,
+                b: &$hir hir::Expr<$hir>,
+                c: rustc_span::symbol::Ident);
+
+            fn check_inline_asm(a: &$hir hir::InlineAsm<$hir>);
+            fn check_anon_const(a: &$hir hir::AnonConst);
+
+            /// Called for the anon const of each inline `const { .. }` block, after
+            /// `check_expr` for the block and before its body is walked. Unlike
+            /// `check_anon_const`, this isn't called for array lengths or const arguments.
+            fn check_inline_const(a: &$hir hir::AnonConst);
+
             fn check_ty(a: &$hir hir::Ty<$hir>);
+
+            /// Called for each lifetime an `impl Trait` type captures, with the `DefId`
+            /// of the lifetime parameter it resolves to. Only lifetimes are reported, so
+            /// the argument is always a `GenericArg::Lifetime`: the type and const
+            /// parameters in scope are always captured, so they aren't in the capture
+            /// list. Lifetimes that aren't captured are bivariant in the opaque type,
+            /// see `variance_of_opaque`.
+            fn check_opaque_captured_arg(a: &$hir hir::GenericArg<$hir>, b: hir::def_id::DefId);
+
+            /// Called for each opaque type definition, i.e. each `impl Trait` in a return
+            /// type or type alias, after `check_item` is called for its item, with the
+            /// `LocalDefId` of that item. Its variances, as computed by
+            /// `variance_of_opaque`, tell which lifetimes it captures.
+            fn check_opaque_ty(a: &$hir hir::OpaqueTy<$hir>, b: hir::def_id::LocalDefId);
+
+            fn check_generic_arg(a: &$hir hir::GenericArg<$hir>);
+
+            /// Called once for the generic arguments of each path segment which has
+            /// some, including method calls, before `check_generic_arg` is called for
+            /// each of them. The `HirId` is the one of the path segment.
+            fn check_generic_args(a: &$hir hir::GenericArgs<$hir>, b: hir::HirId);
+
             fn check_generic_param(a: &$hir hir::GenericParam<$hir>);
             fn check_generics(a: &$hir hir::Generics<$hir>);
+            fn check_where_predicate(a: &$hir hir::WherePredicate<$hir>);
             fn check_poly_trait_ref(a: &$hir hir::PolyTraitRef<$hir>);
             fn check_fn(
                 a: rustc_hir::intravisit::FnKind<$hir>,
@@ -38,14 +124,24 @@ fn check_fn(
                 d: Span,
                 e: hir::HirId);
             fn check_trait_item(a: &$hir hir::TraitItem<$hir>);
+            fn check_trait_item_post(a: &$hir hir::TraitItem<$hir>);
             fn check_impl_item(a: &$hir hir::ImplItem<$hir>);
             fn check_impl_item_post(a: &$hir hir::ImplItem<$hir>);
             fn check_struct_def(a: &$hir hir::VariantData<$hir>);
             fn check_field_def(a: &$hir hir::FieldDef<$hir>);
             fn check_variant(a: &$hir hir::Variant<$hir>);
+            fn check_qpath(a: &$hir hir::QPath<$hir>, b: hir::HirId, c: Span);
             fn check_path(a: &hir::Path<$hir>, b: hir::HirId);
+            fn check_path_post(a: &hir::Path<$hir>, b: hir::HirId);
+            fn check_lifetime(a: &$hir hir::Lifetime);
             fn check_attribute(a: &$hir ast::Attribute);
 
+            /// Called with all the attributes of a node, before `check_attribute` is
+            /// called for each of them. The `HirId` is the node the attributes belong
+            /// to, so crate-level attributes (`#![...]`) can be told apart from the
+            /// others by comparing it to `CRATE_HIR_ID`.
+            fn check_attributes(a: &$hir [ast::Attribute], b: hir::HirId);
+
             /// Called when entering a syntax node that can have lint attributes such
             /// as `#[allow(...)]`. Called with *all* the attributes of that node.
             fn enter_lint_attrs(a: &$hir [ast::Attribute]);
@@ -69,6 +165,13 @@ macro_rules! declare_late_lint_pass {
     ([], [$hir:tt], [$($(#[$attr:meta])* fn $name:ident($($param:ident: $arg:ty),*);)*]) => (
         pub trait LateLintPass<$hir>: LintPass {
             $(#[inline(always)] fn $name(&mut self, _: &LateContext<$hir>, $(_: $arg),*) {})*
+
+            /// The kinds of definitions this pass is interested in. Item and fn callbacks
+            /// (`check_item`, `check_fn`, ...) are not invoked for definitions of any other
+            /// kind. Returns `None`, meaning all kinds, by default.
+            fn applicable_def_kinds(&self) -> Option<&'static [DefKind]> {
+                None
+            }
         }
     )
 }
@@ -113,6 +216,9 @@ impl $name {
                 $(lints.extend_from_slice(&$passes::get_lints());)*
                 lints
             }
+
+            /// The names of the passes combined into this one.
+            $v const PASS_NAMES: &'static [&'static str] = &[$(stringify!($passes)),*];
         }
 
         impl<'tcx> LateLintPass<'tcx> for $name {
@@ -2721,6 +2721,65 @@
     };
 }
 
+declare_lint! {
+    /// The `foreign_generic_const_exprs` lint detects generic constants from
+    /// crates using `#![feature(generic_const_exprs)]` which can't be evaluated
+    /// in a crate that doesn't enable that feature.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,aux
+    /// #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    ///
+    /// use std::str::FromStr;
+    ///
+    /// pub struct If<const CONDITION: bool>;
+    /// pub trait True {}
+    /// impl True for If<true> {}
+    ///
+    /// pub struct Foo<const N: u32>;
+    /// impl<const N: u32> FromStr for Foo<N>
+    /// where
+    ///     If<{ N <= 32 }>: True,
+    /// {
+    ///     type Err = ();
+    ///     fn from_str(_: &str) -> Result<Self, Self::Err> { Ok(Foo) }
+    /// }
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// use std::str::FromStr;
+    ///
+    /// fn main() {
+    ///     let _ = <aux::Foo<16>>::from_str("");
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Constants using generic parameters can only be checked against the
+    /// where clauses of an item with `#![feature(generic_const_exprs)]`. If
+    /// the current crate doesn't enable the feature, such constants from other
+    /// crates can't be evaluated. This used to abort compilation, and is a
+    /// [future-incompatible] lint so that the rest of the crate can still be
+    /// checked. If the lint is allowed or only warned about, such constants are
+    /// only evaluated once their generic parameters are known, after
+    /// monomorphization. Enabling the feature in the current crate fixes it. See
+    /// [issue #76560] for the tracking issue of the feature.
+    ///
+    /// [future-incompatible]: ../index.md#future-incompatible-lints
+    /// [issue #76560]: https://github.com/rust-lang/rust/issues/76560
+    pub FOREIGN_GENERIC_CONST_EXPRS,
+    Deny,
+    "detects generic constants from crates using `generic_const_exprs` which can't be evaluated",
+    @future_incompatible = FutureIncompatibleInfo {
+        reference: "issue #76560 <https://github.com/rust-lang/rust/issues/76560>",
+    };
+}
+
 declare_lint! {
     /// The `function_item_references` lint detects function references that are
     /// formatted with [`fmt::Pointer`] or transmuted.
@@ -3241,6 +3300,7 @@
         FUZZY_PROVENANCE_CASTS,
         LOSSY_PROVENANCE_CASTS,
         CONST_EVALUATABLE_UNCHECKED,
+        FOREIGN_GENERIC_CONST_EXPRS,
         INEFFECTIVE_UNSTABLE_TRAIT_IMPL,
         MUST_NOT_SUSPEND,
         UNINHABITED_STATIC,
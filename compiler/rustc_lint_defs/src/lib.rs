@@ -617,10 +617,12 @@ pub fn buffer_lint_with_diagnostic(
 /// ```
 ///
 /// The `{{produces}}` tag will be automatically replaced with the output from
-/// the example by the build system. If the lint example is too complex to run
-/// as a simple example (for example, it needs an extern crate), mark the code
-/// block with `ignore` and manually replace the `{{produces}}` line with the
-/// expected output in a `text` code block.
+/// the example by the build system. If the example needs an extern crate, put
+/// that crate in a preceding code block marked with `aux`: it is built as the
+/// crate `aux` and passed to the examples following it. If the lint example is
+/// otherwise too complex to run as a simple example, mark the code block with
+/// `ignore` and manually replace the `{{produces}}` line with the expected
+/// output in a `text` code block.
 ///
 /// If this is a rustdoc-only lint, then only include a brief introduction
 /// with a link with the text `[rustdoc book]` so that the validator knows
@@ -2,6 +2,7 @@
 
 use rustc_data_structures::fx::FxHashMap;
 use rustc_data_structures::sorted_map::SortedMap;
+use rustc_data_structures::sync::Lock;
 use rustc_errors::{Diagnostic, DiagnosticBuilder, DiagnosticId, DiagnosticMessage, MultiSpan};
 use rustc_hir::{HirId, ItemLocalId};
 use rustc_session::lint::{
@@ -13,7 +14,7 @@
 use rustc_span::source_map::{DesugaringKind, ExpnKind};
 use rustc_span::{symbol, Span, Symbol, DUMMY_SP};
 
-use crate::ty::TyCtxt;
+use crate::ty::{tls, TyCtxt};
 
 /// How a lint level was set.
 #[derive(Clone, Copy, PartialEq, Eq, HashStable, Debug)]
@@ -465,11 +466,40 @@ fn struct_lint_level_impl(
         // Finally, run `decorate`.
         decorate(&mut err);
         explain_lint_level_source(lint, level, src, &mut *err);
-        err.emit()
+        emit_or_buffer(err)
     }
     struct_lint_level_impl(sess, lint, level, src, span, msg, Box::new(decorate))
 }
 
+/// Runs `f`, buffering the lints the current query job emits through `struct_lint_level`
+/// instead of emitting them. The buffered lints are returned alongside the result of
+/// `f`, and it is up to the caller to emit them, e.g. with `Handler::emit_diagnostic`.
+///
+/// The lints emitted by the queries `f` executes are not buffered, they belong to those
+/// queries and are emitted as usual. Neither are the lints which only fulfill a
+/// `#[expect]`, as they are never shown. The buffered lints are not recorded as side
+/// effects of the current query, so they aren't replayed when it is green.
+pub fn with_buffered_lints<R>(f: impl FnOnce() -> R) -> (R, Vec<Diagnostic>) {
+    let buffered = Lock::new(Vec::new());
+    let result = tls::with_context(|icx| {
+        let icx = tls::ImplicitCtxt { buffered_lints: Some(&buffered), ..icx.clone() };
+        tls::enter_context(&icx, |_| f())
+    });
+    (result, buffered.into_inner())
+}
+
+fn emit_or_buffer(mut err: DiagnosticBuilder<'_, ()>) {
+    tls::with_context_opt(|icx| {
+        if let Some(buffered) = icx.and_then(|icx| icx.buffered_lints) {
+            if let Some((diag, _)) = err.into_diagnostic() {
+                buffered.lock().push(diag);
+            }
+        } else {
+            err.emit();
+        }
+    })
+}
+
 /// Returns whether `span` originates in a foreign crate's external macro.
 ///
 /// This is used to test whether a lint should not even begin to figure out whether it should
@@ -667,19 +667,84 @@
         separate_provide_extern
     }
 
-    /// Gets a map with the variance of every item; use `item_variance` instead.
+    /// Gets a map with the variance of every item, gathered from `component_variances`.
+    /// This depends on every item of the crate, so use `variances_of` instead.
     query crate_variances(_: ()) -> ty::CrateVariancesMap<'tcx> {
         arena_cache
         desc { "computing the variances for items in this crate" }
     }
 
+    /// Groups the items of the crate whose variances are inferred into the components
+    /// whose variances have to be inferred together.
+    query variance_components(_: ()) -> ty::VarianceComponents {
+        arena_cache
+        desc { "finding the items whose variances depend on each other" }
+    }
+
+    /// Gets the items of the component of `variance_components` which `key` is in,
+    /// the first of which is its root, or nothing if the variances of `key` aren't
+    /// inferred. The components of the other items changing doesn't invalidate this.
+    query variance_component_of(key: LocalDefId) -> &'tcx [LocalDefId] {
+        desc { |tcx| "finding the items whose variances depend on those of `{}`", tcx.def_path_str(key.to_def_id()) }
+    }
+
+    /// Infers the variances of the items of the component of `variance_components`
+    /// whose first item is `key`. Used by `variances_of`, so that changing an item only
+    /// invalidates the variances of the components depending on it.
+    query component_variances(key: LocalDefId) -> ty::CrateVariancesMap<'tcx> {
+        arena_cache
+        desc { |tcx| "computing the variances of the items depending on `{}`", tcx.def_path_str(key.to_def_id()) }
+    }
+
+    /// Lists the items of this crate whose variance was inferred, along with the
+    /// indices of those of their generic parameters which are invariant or
+    /// bivariant. Items where every parameter is covariant or contravariant are
+    /// left out. Built from `crate_variances`.
+    query crate_invariant_params(_: ()) -> Vec<(DefId, Vec<u32>)> {
+        arena_cache
+        desc { "collecting the invariant and bivariant parameters of items in this crate" }
+    }
+
+    /// Finds the generic parameters of an ADT which are only invariant because its
+    /// `PhantomData` fields use them both covariantly and contravariantly. Returns
+    /// the index of each such parameter, along with the span of a field using it
+    /// covariantly and of one using it contravariantly. Used for diagnostics.
+    query phantom_data_variance_conflicts(key: LocalDefId) -> &'tcx [(u32, Span, Span)] {
+        desc { |tcx| "checking the `PhantomData` fields of `{}` for variance conflicts", tcx.def_path_str(key.to_def_id()) }
+    }
+
+    /// Finds the generic parameters of an ADT which are only invariant because of
+    /// the `*mut` raw pointers in its fields, i.e. which wouldn't be invariant if
+    /// those were `*const` instead. Returns the index of each such parameter, along
+    /// with a field using it in such a pointer. Used by the `invariant_raw_pointer_field`
+    /// lint.
+    query raw_pointer_invariant_params(key: LocalDefId) -> &'tcx [(u32, DefId)] {
+        desc { |tcx| "checking whether `{}` is invariant because of raw pointers", tcx.def_path_str(key.to_def_id()) }
+    }
+
     /// Maps from the `DefId` of a type or region parameter to its (inferred) variance.
+    ///
+    /// Like `generics_of`, this includes the parameters of the parent, so the
+    /// variances of an associated fn start with those of its trait's `Self` and
+    /// parameters, or its impl's parameters, as they are used by the fn.
     query variances_of(def_id: DefId) -> &'tcx [ty::Variance] {
         desc { |tcx| "computing the variances of `{}`", tcx.def_path_str(def_id) }
         cache_on_disk_if { def_id.is_local() }
         separate_provide_extern
     }
 
+    /// Pairs the variances of `variances_of` with the name and kind of their generic
+    /// parameter, including those of the parent generics, which come first.
+    query variances_with_params_of(def_id: DefId) -> &'tcx [(Symbol, ty::GenericParamDefKind, ty::Variance)] {
+        desc { |tcx| "pairing the variances of `{}` with their parameters", tcx.def_path_str(def_id) }
+    }
+
+    /// For each generic parameter of an opaque type, the span of the bound that
+    /// made it a captured (invariant) lifetime, if any.
+    query opaque_lifetime_capture_spans(key: LocalDefId) -> &'tcx [Option<Span>] {
+        desc { |tcx| "computing the lifetime captures of `{}`", tcx.def_path_str(key.to_def_id()) }
+    }
+
     /// Maps from thee `DefId` of a type to its (inferred) outlives.
     query inferred_outlives_crate(_: ()) -> ty::CratePredicatesMap<'tcx> {
         arena_cache
@@ -813,9 +878,14 @@
         cycle_delay_bug
     }
 
-    /// Performs lint checking for the module.
-    query lint_mod(key: LocalDefId) -> () {
+    /// Performs lint checking for the module. The lints are returned rather than
+    /// emitted, errors included, so that `rustc_lint::check_crate` can emit those of
+    /// all the modules in a deterministic order.
+    query lint_mod(key: LocalDefId) -> Vec<rustc_errors::Diagnostic> {
+        arena_cache
+        no_hash
         desc { |tcx| "linting {}", describe_as_module(key, tcx) }
+        cache_on_disk_if { true }
     }
 
     /// Checks the attributes in the module.
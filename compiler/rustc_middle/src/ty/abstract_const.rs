@@ -5,6 +5,7 @@
 };
 use rustc_errors::ErrorGuaranteed;
 use rustc_hir::def_id::DefId;
+use rustc_span::Span;
 
 #[derive(Hash, Debug, Clone, Copy, Ord, PartialOrd, PartialEq, Eq)]
 #[derive(TyDecodable, TyEncodable, HashStable, TypeVisitable, TypeFoldable)]
@@ -18,7 +19,9 @@ pub enum CastKind {
 #[derive(Debug, Copy, Clone, PartialEq, Eq, HashStable, TyEncodable, TyDecodable)]
 pub enum NotConstEvaluatable {
     Error(ErrorGuaranteed),
-    MentionsInfer,
+    /// The constant mentions inference variables. Points at the const argument
+    /// which could not be inferred, if known.
+    MentionsInfer(Span),
     MentionsParam,
 }
 
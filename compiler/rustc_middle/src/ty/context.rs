@@ -1928,6 +1928,11 @@ pub struct ImplicitCtxt<'a, 'tcx> {
         /// This is updated by `JobOwner::start` in `ty::query::plumbing` when executing a query.
         pub diagnostics: Option<&'a Lock<ThinVec<Diagnostic>>>,
 
+        /// Where to buffer the lints emitted by the current query job instead of emitting
+        /// them, if anywhere. This is set by `rustc_middle::lint::with_buffered_lints` and,
+        /// unlike `diagnostics`, not passed on to the queries the job executes.
+        pub buffered_lints: Option<&'a Lock<Vec<Diagnostic>>>,
+
         /// Used to prevent queries from calling too deeply.
         pub query_depth: usize,
 
@@ -1943,6 +1948,7 @@ pub fn new(gcx: &'tcx GlobalCtxt<'tcx>) -> Self {
                 tcx,
                 query: None,
                 diagnostics: None,
+                buffered_lints: None,
                 query_depth: 0,
                 task_deps: TaskDepsRef::Ignore,
             }
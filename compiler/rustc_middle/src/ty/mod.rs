@@ -437,6 +437,19 @@ pub struct CrateVariancesMap<'tcx> {
     pub variances: FxHashMap<DefId, &'tcx [ty::Variance]>,
 }
 
+/// The items of the local crate whose variances are inferred, grouped into the
+/// strongly connected components of the graph where each item points to the local
+/// items whose variances its own depend on. The variances of the items of a
+/// component are inferred together, see `component_variances`.
+#[derive(HashStable, Debug, Default)]
+pub struct VarianceComponents {
+    /// The index into `components` of the component of each item.
+    pub component_of: FxHashMap<LocalDefId, usize>,
+    /// The items of each component, by `DefIndex`. The components an item
+    /// depends on come before its own.
+    pub components: Vec<Vec<LocalDefId>>,
+}
+
 // Contains information needed to resolve types and (in the future) look up
 // the types of AST nodes.
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
@@ -119,6 +119,7 @@ fn start_query<R>(
                 tcx: **self,
                 query: Some(token),
                 diagnostics,
+                buffered_lints: None,
                 query_depth: current_icx.query_depth + depth_limit as usize,
                 task_deps: current_icx.task_deps,
             };
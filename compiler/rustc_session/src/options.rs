@@ -1264,6 +1264,10 @@ pub(crate) fn parse_proc_macro_execution_strategy(
         "enables drop tracking in generators (default: no)"),
     dual_proc_macros: bool = (false, parse_bool, [TRACKED],
         "load proc macros for both target and host, but only link to the target (default: no)"),
+    dump_const_evaluatable_bounds: bool = (false, parse_bool, [UNTRACKED],
+        "note which `ConstEvaluatable` bound of the environment proved a generic constant \
+        to be evaluatable, and each constant evaluated to check that it is evaluatable \
+        (default: no)"),
     dump_dep_graph: bool = (false, parse_bool, [UNTRACKED],
         "dump the dependency graph to $RUST_DEP_GRAPH (default: /tmp/dep_graph.gv) \
         (default: no)"),
@@ -1293,6 +1297,9 @@ pub(crate) fn parse_proc_macro_execution_strategy(
         computed `block` spans (one span encompassing a block's terminator and \
         all statements). If `-Z instrument-coverage` is also enabled, create \
         an additional `.html` file showing the computed coverage spans."),
+    dump_variance_constraints: bool = (false, parse_bool, [UNTRACKED],
+        "note the constraints gathered by variance inference, before they are solved \
+        (default: no)"),
     dwarf_version: Option<u32> = (None, parse_opt_number, [TRACKED],
         "version of DWARF debug information to emit (default: 2 or 4, depending on platform)"),
     dylib_lto: bool = (false, parse_bool, [UNTRACKED],
@@ -1522,6 +1529,9 @@ pub(crate) fn parse_proc_macro_execution_strategy(
         for example: `-Z self-profile-events=default,query-keys`
         all options: none, all, default, generic-activity, query-provider, query-cache-hit
                      query-blocked, incr-cache-load, incr-result-hashing, query-keys, function-args, args, llvm, artifact-sizes"),
+    sequential_late_lints: bool = (false, parse_bool, [UNTRACKED],
+        "run the late lint passes one after another, the per-module ones in `DefId` order, \
+        instead of in parallel (default: no)"),
     share_generics: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "make the current crate share its generic instantiations"),
     show_span: Option<String> = (None, parse_opt_string, [TRACKED],
@@ -1635,6 +1645,11 @@ pub(crate) fn parse_proc_macro_execution_strategy(
         "use legacy .ctors section for initializers rather than .init_array"),
     validate_mir: bool = (false, parse_bool, [UNTRACKED],
         "validate MIR after each transformation"),
+    validate_variance: bool = (false, parse_bool, [UNTRACKED],
+        "check that the inferred variances satisfy all the variance constraints (default: no)"),
+    variance_solver_stats: bool = (false, parse_bool, [UNTRACKED],
+        "note how many rounds variance inference took to solve its constraints, and warn \
+        if that's more than it should ever take (default: no)"),
     #[rustc_lint_opt_deny_field_access("use `Session::verbose` instead of this field")]
     verbose: bool = (false, parse_bool, [UNTRACKED],
         "in general, enable more debug printouts (default: no)"),
@@ -8,13 +8,20 @@
 //! In this case we try to build an abstract representation of this constant using
 //! `thir_abstract_const` which can then be checked for structural equality with other
 //! generic constants mentioned in the `caller_bounds` of the current environment.
+use rustc_hir as hir;
 use rustc_hir::def::DefKind;
+use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::{self, Visitor};
 use rustc_infer::infer::InferCtxt;
-use rustc_middle::mir::interpret::ErrorHandled;
+use rustc_middle::mir::interpret::{ErrorHandled, EvalToValTreeResult};
 
+use rustc_middle::infer::canonical::CanonicalVarKind;
 use rustc_middle::traits::ObligationCause;
 use rustc_middle::ty::abstract_const::NotConstEvaluatable;
+use rustc_middle::ty::subst::GenericArgKind;
 use rustc_middle::ty::{self, TyCtxt, TypeVisitable, TypeVisitor};
+use rustc_session::lint::builtin::FOREIGN_GENERIC_CONST_EXPRS;
+use rustc_session::lint::Level;
 
 use rustc_span::Span;
 use std::ops::ControlFlow;
@@ -27,9 +34,10 @@ pub fn is_const_evaluatable<'tcx>(
     infcx: &InferCtxt<'tcx>,
     ct: ty::Const<'tcx>,
     param_env: ty::ParamEnv<'tcx>,
-    span: Span,
+    cause: &ObligationCause<'tcx>,
 ) -> Result<(), NotConstEvaluatable> {
     let tcx = infcx.tcx;
+    let span = cause.span;
     let uv = match ct.kind() {
         ty::ConstKind::Unevaluated(uv) => uv,
         // FIXME(generic_const_exprs): this seems wrong but I couldn't find a way to get this to trigger
@@ -39,40 +47,20 @@ pub fn is_const_evaluatable<'tcx>(
         | ty::ConstKind::Placeholder(_)
         | ty::ConstKind::Value(_)
         | ty::ConstKind::Error(_) => return Ok(()),
-        ty::ConstKind::Infer(_) => return Err(NotConstEvaluatable::MentionsInfer),
+        ty::ConstKind::Infer(_) => return Err(mentions_infer(infcx, ct, span)),
     };
 
-    if tcx.features().generic_const_exprs {
-        let ct = tcx.expand_abstract_consts(ct);
-
-        let is_anon_ct = if let ty::ConstKind::Unevaluated(uv) = ct.kind() {
-            tcx.def_kind(uv.def.did) == DefKind::AnonConst
-        } else {
-            false
-        };
-
-        if !is_anon_ct {
-            if satisfied_from_param_env(tcx, infcx, ct, param_env) {
-                return Ok(());
-            }
-            if ct.has_non_region_infer() {
-                return Err(NotConstEvaluatable::MentionsInfer);
-            } else if ct.has_non_region_param() {
-                return Err(NotConstEvaluatable::MentionsParam);
-            }
-        }
+    // A constant without inference variables which was proven to be evaluatable
+    // stays so, even if the snapshot it was proven in is rolled back. Region
+    // variables are excluded too, as the proof may have constrained them.
+    let key = infcx.canonicalize_response(param_env.and(ct));
+    let cacheable = key.variables.is_empty();
+    if cacheable && infcx.const_evaluatable_cache.borrow().contains(&key) {
+        return Ok(());
+    }
 
-        let concrete = infcx.const_eval_resolve(param_env, uv, Some(span));
-        match concrete {
-            Err(ErrorHandled::TooGeneric) => Err(NotConstEvaluatable::Error(
-                infcx
-                    .tcx
-                    .sess
-                    .delay_span_bug(span, "Missing value for constant, but no error reported?"),
-            )),
-            Err(ErrorHandled::Reported(e)) => Err(NotConstEvaluatable::Error(e)),
-            Ok(_) => Ok(()),
-        }
+    let result = if tcx.features().generic_const_exprs {
+        is_const_evaluatable_expanded(infcx, tcx.expand_abstract_consts(ct), uv, param_env, span)
     } else {
         // FIXME: We should only try to evaluate a given constant here if it is fully concrete
         // as we don't want to allow things like `[u8; std::mem::size_of::<*mut T>()]`.
@@ -82,39 +70,34 @@ pub fn is_const_evaluatable<'tcx>(
         // and hopefully soon change this to an error.
         //
         // See #74595 for more details about this.
-        let concrete = infcx.const_eval_resolve(param_env, uv, Some(span));
+        let concrete = const_eval_resolve_cached(infcx, param_env, uv, span);
         match concrete {
             // If we're evaluating a generic foreign constant, under a nightly compiler while
-            // the current crate does not enable `feature(generic_const_exprs)`, abort
-            // compilation with a useful error.
-            Err(_)
+            // the current crate does not enable `feature(generic_const_exprs)`, emit a useful
+            // error while still checking the rest of the crate.
+            Err(err)
                 if tcx.sess.is_nightly_build()
                     && satisfied_from_param_env(
                         tcx,
                         infcx,
                         tcx.expand_abstract_consts(ct),
                         param_env,
+                        span,
                     ) =>
             {
-                tcx.sess
-                    .struct_span_fatal(
-                        // Slightly better span than just using `span` alone
-                        if span == rustc_span::DUMMY_SP { tcx.def_span(uv.def.did) } else { span },
-                        "failed to evaluate generic const expression",
-                    )
-                    .note("the crate this constant originates from uses `#![feature(generic_const_exprs)]`")
-                    .span_suggestion_verbose(
-                        rustc_span::DUMMY_SP,
-                        "consider enabling this feature",
-                        "#![feature(generic_const_exprs)]\n",
-                        rustc_errors::Applicability::MaybeIncorrect,
-                    )
-                    .emit()
+                match err {
+                    ErrorHandled::Reported(e) => Err(NotConstEvaluatable::Error(e)),
+                    // Not cached, so that the lint is still emitted when the constant is
+                    // checked outside of snapshots after being accepted in one.
+                    ErrorHandled::TooGeneric => {
+                        return lint_foreign_generic_const_expr(infcx, uv, cause);
+                    }
+                }
             }
 
             Err(ErrorHandled::TooGeneric) => {
                 let err = if uv.has_non_region_infer() {
-                    NotConstEvaluatable::MentionsInfer
+                    mentions_infer(infcx, ct, span)
                 } else if uv.has_non_region_param() {
                     NotConstEvaluatable::MentionsParam
                 } else {
@@ -130,7 +113,189 @@ pub fn is_const_evaluatable<'tcx>(
             Err(ErrorHandled::Reported(e)) => Err(NotConstEvaluatable::Error(e)),
             Ok(_) => Ok(()),
         }
+    };
+    if cacheable && result.is_ok() {
+        infcx.const_evaluatable_cache.borrow_mut().insert(key);
     }
+    result
+}
+
+/// Emits `FOREIGN_GENERIC_CONST_EXPRS` for the constant `uv`, which comes from a crate
+/// using `#![feature(generic_const_exprs)]` and failed to evaluate in the current crate.
+/// Unless the lint is emitted as an error, the constant is considered evaluatable, as
+/// it used to be.
+///
+/// The lint is emitted at the body the obligation comes from, as the constant itself
+/// usually belongs to another crate. Obligations checked after monomorphization have a
+/// dummy cause, whose body is the crate root. Obligations are also evaluated
+/// speculatively, e.g. during selection: in snapshots, the result only depends on the
+/// level of the lint, which is emitted once the obligation is processed outside of them.
+fn lint_foreign_generic_const_expr<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    uv: ty::UnevaluatedConst<'tcx>,
+    cause: &ObligationCause<'tcx>,
+) -> Result<(), NotConstEvaluatable> {
+    let tcx = infcx.tcx;
+    let hir_id = cause.body_id;
+    if infcx.is_in_snapshot() {
+        // The level accounts for `--cap-lints`, so an error-level lint is emitted as an error.
+        return match tcx.lint_level_at_node(FOREIGN_GENERIC_CONST_EXPRS, hir_id).0 {
+            Level::Deny | Level::Forbid => Err(NotConstEvaluatable::MentionsParam),
+            Level::Allow | Level::Expect(_) | Level::Warn | Level::ForceWarn(_) => Ok(()),
+        };
+    }
+
+    // Slightly better span than just using `span` alone
+    let span =
+        if cause.span == rustc_span::DUMMY_SP { tcx.def_span(uv.def.did) } else { cause.span };
+    let note = "the crate this constant originates from uses `#![feature(generic_const_exprs)]`";
+    let msg = "failed to evaluate generic const expression";
+    // Don't report the constant again if the lint was emitted as an error.
+    tcx.sess
+        .track_errors(|| {
+            tcx.struct_span_lint_hir(FOREIGN_GENERIC_CONST_EXPRS, hir_id, span, msg, |lint| {
+                lint.note(note).span_suggestion_verbose(
+                    rustc_span::DUMMY_SP,
+                    "consider enabling this feature",
+                    "#![feature(generic_const_exprs)]\n",
+                    rustc_errors::Applicability::MaybeIncorrect,
+                )
+            })
+        })
+        .map_err(NotConstEvaluatable::Error)
+}
+
+/// Like `is_const_evaluatable`, for callers which already expanded the constant
+/// `uv` with `TyCtxt::expand_abstract_consts`, giving `ct`. Constants are only
+/// expanded with `#![feature(generic_const_exprs)]`, so this must only be used
+/// with that feature enabled.
+#[instrument(skip(infcx), level = "debug")]
+pub fn is_const_evaluatable_expanded<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    ct: ty::Const<'tcx>,
+    uv: ty::UnevaluatedConst<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    span: Span,
+) -> Result<(), NotConstEvaluatable> {
+    let tcx = infcx.tcx;
+    debug_assert!(tcx.features().generic_const_exprs);
+
+    let is_anon_ct = if let ty::ConstKind::Unevaluated(uv) = ct.kind() {
+        tcx.def_kind(uv.def.did) == DefKind::AnonConst
+    } else {
+        false
+    };
+
+    if !is_anon_ct {
+        if satisfied_from_param_env(tcx, infcx, ct, param_env, span) {
+            return Ok(());
+        }
+        if ct.has_non_region_infer() {
+            return Err(mentions_infer(infcx, ct, span));
+        } else if ct.has_non_region_param() {
+            return Err(NotConstEvaluatable::MentionsParam);
+        }
+    }
+
+    let concrete = const_eval_resolve_cached(infcx, param_env, uv, span);
+    match concrete {
+        Err(ErrorHandled::TooGeneric) => Err(NotConstEvaluatable::Error(
+            infcx
+                .tcx
+                .sess
+                .delay_span_bug(span, "Missing value for constant, but no error reported?"),
+        )),
+        Err(ErrorHandled::Reported(e)) => Err(NotConstEvaluatable::Error(e)),
+        Ok(_) => Ok(()),
+    }
+}
+
+/// Like `InferCtxt::const_eval_resolve`, but remembers the constants without
+/// inference variables which are too generic to be evaluated, so that checking
+/// the same obligation again, e.g. while backtracking during selection, doesn't
+/// evaluate it again. Constants with inference variables may become evaluatable
+/// once those are resolved, so they aren't cached. Region variables don't matter,
+/// as constants are evaluated with erased regions. Errors aren't cached either:
+/// they are already reported, and evaluating the constant again is cached by the
+/// query system.
+fn const_eval_resolve_cached<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    uv: ty::UnevaluatedConst<'tcx>,
+    span: Span,
+) -> EvalToValTreeResult<'tcx> {
+    let key = infcx.canonicalize_response(param_env.and(uv));
+    let cacheable = key.variables.iter().all(|var| matches!(var.kind, CanonicalVarKind::Region(_)));
+    if cacheable && infcx.const_eval_failure_cache.borrow().contains(&key) {
+        return Err(ErrorHandled::TooGeneric);
+    }
+
+    if infcx.tcx.sess.opts.unstable_opts.dump_const_evaluatable_bounds {
+        note_evaluated_const(infcx.tcx, uv, span);
+    }
+    let result = infcx.const_eval_resolve(param_env, uv, Some(span));
+    if cacheable && let Err(ErrorHandled::TooGeneric) = result {
+        infcx.const_eval_failure_cache.borrow_mut().insert(key);
+    }
+    result
+}
+
+/// Builds the error for a constant which can't be evaluated yet because it
+/// mentions inference variables, pointing at the first const argument in it
+/// which hasn't been inferred. Falls back to `span` if there is none.
+fn mentions_infer<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    ct: ty::Const<'tcx>,
+    span: Span,
+) -> NotConstEvaluatable {
+    NotConstEvaluatable::MentionsInfer(uninferred_const_arg_span(infcx, ct).unwrap_or(span))
+}
+
+/// Returns the span of the first const argument in `ct` which hasn't been inferred,
+/// as recorded in the origin of its inference variable. This is the span of
+/// `NotConstEvaluatable::MentionsInfer`, which ambiguity errors can get from here
+/// without checking whether `ct` is evaluatable again.
+pub fn uninferred_const_arg_span<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    ct: ty::Const<'tcx>,
+) -> Option<Span> {
+    infcx
+        .resolve_vars_if_possible(ct)
+        .walk()
+        .find_map(|arg| match arg.unpack() {
+            GenericArgKind::Const(c) => infcx.const_var_origin(c),
+            _ => None,
+        })
+        .map(|origin| origin.span)
+        .filter(|span| !span.is_dummy())
+}
+
+/// Checks whether the abstract consts `a` and `b` are structurally equal, e.g.
+/// `N + 1` and `N + 1`, but neither `1 + N` nor `N + 2`. The abstract consts
+/// nested in `a` and `b` are expanded as they are related, so `a` and `b` only
+/// need to be expanded at their root, e.g. with `expand_abstract_const_shallow`.
+/// Inference variables are unified along the way, which is committed to `infcx`
+/// if the consts are equal.
+#[instrument(skip(infcx), level = "debug", ret)]
+pub fn try_unify_abstract_consts<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    a: ty::Const<'tcx>,
+    b: ty::Const<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+) -> bool {
+    infcx
+        .commit_if_ok(|_| {
+            let ocx = ObligationCtxt::new_in_snapshot(infcx);
+            if let Ok(()) = ocx.eq(&ObligationCause::dummy(), param_env, a.ty(), b.ty())
+                && let Ok(()) = ocx.eq(&ObligationCause::dummy(), param_env, a, b)
+                && ocx.select_all_or_error().is_empty()
+            {
+                Ok(())
+            } else {
+                Err(())
+            }
+        })
+        .is_ok()
 }
 
 #[instrument(skip(infcx, tcx), level = "debug")]
@@ -139,30 +304,31 @@ fn satisfied_from_param_env<'tcx>(
     infcx: &InferCtxt<'tcx>,
     ct: ty::Const<'tcx>,
     param_env: ty::ParamEnv<'tcx>,
+    span: Span,
 ) -> bool {
     // Try to unify with each subtree in the AbstractConst to allow for
     // `N + 1` being const evaluatable even if theres only a `ConstEvaluatable`
-    // predicate for `(N + 1) * 2`
+    // predicate for `(N + 1) * 2`. The subtrees are only expanded as they are
+    // visited, so that the parts of the bound after the first subtree which
+    // unifies are never expanded.
     struct Visitor<'a, 'tcx> {
         ct: ty::Const<'tcx>,
         param_env: ty::ParamEnv<'tcx>,
 
         infcx: &'a InferCtxt<'tcx>,
+        /// The number of abstract consts expanded so far, for
+        /// `-Z dump-const-evaluatable-bounds`.
+        expanded: usize,
     }
     impl<'a, 'tcx> TypeVisitor<'tcx> for Visitor<'a, 'tcx> {
         type BreakTy = ();
         fn visit_const(&mut self, c: ty::Const<'tcx>) -> ControlFlow<Self::BreakTy> {
-            if let Ok(()) = self.infcx.commit_if_ok(|_| {
-                let ocx = ObligationCtxt::new_in_snapshot(self.infcx);
-                if let Ok(()) = ocx.eq(&ObligationCause::dummy(), self.param_env, c.ty(), self.ct.ty())
-                    && let Ok(()) = ocx.eq(&ObligationCause::dummy(), self.param_env, c, self.ct)
-                    && ocx.select_all_or_error().is_empty()
-                {
-                    Ok(())
-                } else {
-                    Err(())
-                }
-            }) {
+            let expanded = expand_abstract_const_shallow(self.infcx.tcx, c);
+            if expanded != c {
+                self.expanded += 1;
+            }
+            let c = expanded;
+            if try_unify_abstract_consts(self.infcx, c, self.ct, self.param_env) {
                 ControlFlow::BREAK
             } else if let ty::ConstKind::Expr(e) = c.kind() {
                 e.visit_with(self)
@@ -182,12 +348,21 @@ fn visit_const(&mut self, c: ty::Const<'tcx>) -> ControlFlow<Self::BreakTy> {
     for pred in param_env.caller_bounds() {
         match pred.kind().skip_binder() {
             ty::PredicateKind::ConstEvaluatable(ce) => {
-                let b_ct = tcx.expand_abstract_consts(ce);
-                let mut v = Visitor { ct, infcx, param_env };
+                // Relating consts only expands them with `feature(generic_const_exprs)`,
+                // so the bound has to be expanded up front without it.
+                let b_ct = if tcx.features().generic_const_exprs {
+                    ce
+                } else {
+                    tcx.expand_abstract_consts(ce)
+                };
+                let mut v = Visitor { ct, infcx, param_env, expanded: 0 };
                 let result = b_ct.visit_with(&mut v);
 
                 if let ControlFlow::Break(()) = result {
-                    debug!("is_const_evaluatable: abstract_const ~~> ok");
+                    debug!(?pred, "is_const_evaluatable: abstract_const ~~> ok");
+                    if tcx.sess.opts.unstable_opts.dump_const_evaluatable_bounds {
+                        note_satisfying_bound(tcx, ct, ce, v.expanded, span);
+                    }
                     return true;
                 }
             }
@@ -197,3 +372,166 @@ fn visit_const(&mut self, c: ty::Const<'tcx>) -> ControlFlow<Self::BreakTy> {
 
     false
 }
+
+/// Expands `c` if it is an abstract const, like `TyCtxt::expand_abstract_consts`,
+/// but leaves the abstract consts it is made of unexpanded.
+fn expand_abstract_const_shallow<'tcx>(tcx: TyCtxt<'tcx>, c: ty::Const<'tcx>) -> ty::Const<'tcx> {
+    match c.kind() {
+        ty::ConstKind::Unevaluated(uv) => match tcx.bound_abstract_const(uv.def) {
+            Err(e) => tcx.const_error_with_guaranteed(c.ty(), e),
+            Ok(Some(bac)) => {
+                let substs = tcx.erase_regions(uv.substs);
+                bac.subst(tcx, substs)
+            }
+            Ok(None) => c,
+        },
+        _ => c,
+    }
+}
+
+/// Finds the innermost part of the abstract const `ct` which mentions generic
+/// parameters and isn't known to be evaluatable from the `ConstEvaluatable` bounds
+/// of `param_env`, e.g. `size_of::<U>()` in `size_of::<T>() + size_of::<U>()`
+/// with a bound on `size_of::<T>()`, and returns the span of the expression it
+/// was built from. As abstract consts don't remember spans, this is only found
+/// for function calls and named constants, and `None` is returned otherwise, or
+/// if that part is `ct` as a whole.
+pub fn too_generic_subexpr_span<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    ct: ty::Const<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+) -> Option<Span> {
+    let tcx = infcx.tcx;
+    let ty::ConstKind::Unevaluated(uv) = ct.kind() else { return None };
+    let (part, owner) = innermost_too_generic_part(infcx, ct, uv.def.did, param_env)?;
+    if part == expand_abstract_const_shallow(tcx, ct) {
+        return None;
+    }
+
+    let owner = owner.as_local()?;
+    let body = tcx.hir().body(tcx.hir().body_owned_by(owner));
+    let mut finder = SubexprFinder { tcx, typeck_results: tcx.typeck(owner), part, span: None };
+    finder.visit_expr(body.value);
+    finder.span
+}
+
+/// Returns the innermost part of `ct` which mentions generic parameters and isn't
+/// known to be evaluatable, along with the constant whose body it comes from, which
+/// is `owner` unless `ct` is an abstract const itself.
+fn innermost_too_generic_part<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    ct: ty::Const<'tcx>,
+    owner: DefId,
+    param_env: ty::ParamEnv<'tcx>,
+) -> Option<(ty::Const<'tcx>, DefId)> {
+    let tcx = infcx.tcx;
+    // Parameters on their own are always evaluatable.
+    if let ty::ConstKind::Param(_) = ct.kind() {
+        return None;
+    }
+    if !ct.has_non_region_param()
+        || satisfied_from_param_env(tcx, infcx, ct, param_env, rustc_span::DUMMY_SP)
+    {
+        return None;
+    }
+
+    let expanded = expand_abstract_const_shallow(tcx, ct);
+    let owner = match ct.kind() {
+        ty::ConstKind::Unevaluated(uv) if expanded != ct => uv.def.did,
+        _ => owner,
+    };
+    if let ty::ConstKind::Expr(e) = expanded.kind() {
+        let operands = match e {
+            ty::Expr::Binop(_, lhs, rhs) => vec![lhs, rhs],
+            ty::Expr::UnOp(_, operand) | ty::Expr::Cast(_, operand, _) => vec![operand],
+            // The callee is only too generic if the call is.
+            ty::Expr::FunctionCall(_, args) => args.to_vec(),
+        };
+        let inner = operands
+            .into_iter()
+            .find_map(|operand| innermost_too_generic_part(infcx, operand, owner, param_env));
+        if inner.is_some() {
+            return inner;
+        }
+    }
+    Some((expanded, owner))
+}
+
+/// Finds the expression a part of an abstract const was built from, for
+/// `too_generic_subexpr_span`.
+struct SubexprFinder<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    typeck_results: &'tcx ty::TypeckResults<'tcx>,
+    part: ty::Const<'tcx>,
+    span: Option<Span>,
+}
+
+impl<'tcx> SubexprFinder<'tcx> {
+    fn builds_part(&self, expr: &'tcx hir::Expr<'tcx>) -> bool {
+        let tcx = self.tcx;
+        let typeck_results = self.typeck_results;
+        match (self.part.kind(), &expr.kind) {
+            (ty::ConstKind::Expr(ty::Expr::FunctionCall(callee, _)), hir::ExprKind::Call(f, _)) => {
+                tcx.erase_regions(typeck_results.expr_ty(f)) == tcx.erase_regions(callee.ty())
+            }
+            (
+                ty::ConstKind::Expr(ty::Expr::FunctionCall(callee, _)),
+                hir::ExprKind::MethodCall(..),
+            ) => typeck_results.type_dependent_def_id(expr.hir_id).map_or(false, |def_id| {
+                let substs = typeck_results.node_substs(expr.hir_id);
+                tcx.erase_regions(tcx.mk_fn_def(def_id, substs)) == tcx.erase_regions(callee.ty())
+            }),
+            (ty::ConstKind::Unevaluated(uv), hir::ExprKind::Path(qpath)) => {
+                typeck_results.qpath_res(qpath, expr.hir_id).opt_def_id() == Some(uv.def.did)
+                    && tcx.erase_regions(typeck_results.node_substs(expr.hir_id))
+                        == tcx.erase_regions(uv.substs)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for SubexprFinder<'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        if self.span.is_none() && self.builds_part(expr) {
+            self.span = Some(expr.span);
+        }
+        intravisit::walk_expr(self, expr);
+    }
+}
+
+/// Notes that the constant `uv` is evaluated to check that it is evaluatable, for
+/// `-Z dump-const-evaluatable-bounds`.
+fn note_evaluated_const<'tcx>(tcx: TyCtxt<'tcx>, uv: ty::UnevaluatedConst<'tcx>, span: Span) {
+    let msg =
+        format!("evaluating `{}` to check that it is evaluatable", tcx.def_path_str(uv.def.did));
+    let mut diag = tcx.sess.struct_note_without_error(msg);
+    if !span.is_dummy() {
+        diag.set_span(span);
+    }
+    diag.emit();
+}
+
+/// Notes that `ct` was proven to be evaluatable by the `ConstEvaluatable` bound on
+/// `bound`, after expanding `expanded` of the abstract consts the bound is made of,
+/// for `-Z dump-const-evaluatable-bounds`.
+fn note_satisfying_bound<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    ct: ty::Const<'tcx>,
+    bound: ty::Const<'tcx>,
+    expanded: usize,
+    span: Span,
+) {
+    let msg = format!(
+        "`{ct}` is evaluatable because of the bound on `{bound}`, \
+         after expanding {expanded} abstract consts of it"
+    );
+    let mut diag = tcx.sess.struct_note_without_error(msg);
+    if !span.is_dummy() {
+        diag.set_span(span);
+    }
+    if let ty::ConstKind::Unevaluated(uv) = bound.kind() {
+        diag.span_note(tcx.def_span(uv.def.did), "the bound is declared here");
+    }
+    diag.emit();
+}
@@ -10,6 +10,7 @@
 use crate::infer::error_reporting::{TyCategory, TypeAnnotationNeeded as ErrorCode};
 use crate::infer::type_variable::{TypeVariableOrigin, TypeVariableOriginKind};
 use crate::infer::{self, InferCtxt};
+use crate::traits::const_evaluatable::{too_generic_subexpr_span, uninferred_const_arg_span};
 use crate::traits::query::evaluate_obligation::InferCtxtExt as _;
 use crate::traits::query::normalize::QueryNormalizeExt as _;
 use crate::traits::specialize::to_pretty_impl_header;
@@ -1270,7 +1271,7 @@ fn report_selection_error(
                 report_object_safety_error(self.tcx, span, did, violations)
             }
 
-            SelectionError::NotConstEvaluatable(NotConstEvaluatable::MentionsInfer) => {
+            SelectionError::NotConstEvaluatable(NotConstEvaluatable::MentionsInfer(_)) => {
                 bug!(
                     "MentionsInfer should have been handled in `traits/fulfill.rs` or `traits/select/mod.rs`"
                 )
@@ -1307,6 +1308,15 @@ fn report_selection_error(
                             )),
                             _ => err.help("consider adding a `where` bound using this expression"),
                         };
+                        let ct = self.resolve_vars_if_possible(ct);
+                        if let Some(part_span) =
+                            too_generic_subexpr_span(self, ct, obligation.param_env)
+                        {
+                            err.span_note(
+                                part_span,
+                                "this part of the constant depends on generic parameters",
+                            );
+                        }
                         err
                     }
                     _ => {
@@ -2371,13 +2381,20 @@ fn visit_expr(&mut self, ex: &'v hir::Expr<'v>) {
                 }
                 let subst = data.walk().find(|g| g.is_non_region_infer());
                 if let Some(subst) = subst {
-                    let err = self.emit_inference_failure_err(
+                    let mut err = self.emit_inference_failure_err(
                         body_id,
                         span,
                         subst,
                         ErrorCode::E0284,
                         true,
                     );
+                    // Point at the const argument which couldn't be inferred, unless
+                    // the error already does.
+                    if let Some(infer_span) = uninferred_const_arg_span(self, data)
+                        && Some(infer_span) != err.span.primary_span()
+                    {
+                        err.span_label(infer_span, "cannot infer the value of this const argument");
+                    }
                     err
                 } else {
                     // If we can't find a substitution, just print a generic error
@@ -434,10 +434,10 @@ fn process_obligation(
                         self.selcx.infcx,
                         uv,
                         obligation.param_env,
-                        obligation.cause.span,
+                        &obligation.cause,
                     ) {
                         Ok(()) => ProcessResult::Changed(vec![]),
-                        Err(NotConstEvaluatable::MentionsInfer) => {
+                        Err(NotConstEvaluatable::MentionsInfer(_)) => {
                             pending_obligation.stalled_on.clear();
                             pending_obligation.stalled_on.extend(
                                 uv.walk().filter_map(TyOrConstInferVar::maybe_from_generic_arg),
@@ -852,10 +852,10 @@ fn evaluate_predicate_recursively<'o>(
                         self.infcx,
                         uv,
                         obligation.param_env,
-                        obligation.cause.span,
+                        &obligation.cause,
                     ) {
                         Ok(()) => Ok(EvaluatedToOk),
-                        Err(NotConstEvaluatable::MentionsInfer) => Ok(EvaluatedToAmbig),
+                        Err(NotConstEvaluatable::MentionsInfer(_)) => Ok(EvaluatedToAmbig),
                         Err(NotConstEvaluatable::MentionsParam) => Ok(EvaluatedToErr),
                         Err(_) => Ok(EvaluatedToErr),
                     }
@@ -0,0 +1,17 @@
+// revisions: cfail1 cfail2 cfail3
+// compile-flags: -Z threads=4
+// build-pass
+
+// Check that the lints of the module passes are emitted again when their
+// modules are green, as they are returned by `lint_mod` instead of being
+// replayed as side effects.
+
+#![allow(dead_code)]
+
+fn Foo() {} //~ WARNING should have a snake case name
+
+mod a {
+    fn Bar() {} //~ WARNING should have a snake case name
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+// Test that changing the variances of a type, and which types its variances depend
+// on, recomputes the components of the crate, but doesn't cause the variances of the
+// types unrelated to it to be computed again: they are loaded from the cache. Their
+// components are the same, so `component_variances` is green for them as well.
+
+// revisions: cfail1 cfail2
+// compile-flags: -Z query-dep-graph
+// build-pass
+
+#![feature(rustc_attrs)]
+#![allow(dead_code)]
+#![crate_type = "rlib"]
+
+#[cfg(cfail1)]
+pub struct Changed<T> {
+    x: T,
+}
+
+// `Changed` and `UsesChanged` now depend on each other's variances, which merges
+// their components.
+#[cfg(cfail2)]
+pub struct Changed<T> {
+    x: fn(T),
+    uses_changed: Option<Box<UsesChanged<T>>>,
+}
+
+pub struct UsesChanged<T> {
+    x: Changed<T>,
+}
+
+#[rustc_clean(cfg = "cfail2", except = "variance_components", loaded_from_disk = "variances_of")]
+pub struct Unrelated<'a, T> {
+    x: &'a T,
+}
+
+#[rustc_clean(cfg = "cfail2", except = "variance_components", loaded_from_disk = "variances_of")]
+pub struct UsesUnrelated<'a, T> {
+    x: Unrelated<'a, T>,
+}
@@ -0,0 +1,24 @@
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+trait Tr<const K: usize> {
+    fn f()
+    where
+        [(); K + 1 + K * 2]:;
+}
+
+struct W<const N: usize>;
+
+// Checking that the bound of `W::f` is implied by the one of `Tr::f` substitutes
+// `{ N * 2 }` for `K` in the latter, so it is made of nested abstract consts.
+// `{ N * 2 } + 1` is found near its root, so only the bound itself should be
+// expanded to find it, and not the `{ N * 2 }` in `K * 2`.
+impl<const N: usize> Tr<{ N * 2 }> for W<N> {
+    fn f()
+    where
+        [(); N * 2 + 1]:,
+    {
+    }
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+trait Pick<const N: usize> {}
+
+macro_rules! impls {
+    ($($n:literal)*) => {
+        $(impl Pick<$n> for [(); $n + 1] {})*
+    };
+}
+
+impls!(0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31);
+
+fn pick<const N: usize>()
+where
+    [(); N + 1]: Pick<N>,
+{
+}
+
+fn main() {
+    pick::<{ 3 * 5 }>();
+}
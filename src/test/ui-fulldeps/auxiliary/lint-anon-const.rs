@@ -0,0 +1,37 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+
+declare_lint!(ANON_CONSTS, Warn, "Report the type of each anonymous constant");
+
+declare_lint_pass!(Pass => [ANON_CONSTS]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_anon_const(&mut self, cx: &LateContext<'tcx>, c: &'tcx hir::AnonConst) {
+        let body = cx.tcx.hir().body(c.body);
+        let ty = cx.typeck_results().expr_ty(body.value);
+        cx.struct_span_lint(
+            ANON_CONSTS,
+            body.value.span,
+            format!("anonymous constant of type `{ty}`"),
+            |lint| lint,
+        );
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&ANON_CONSTS]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
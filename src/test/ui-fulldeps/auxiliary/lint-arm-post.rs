@@ -0,0 +1,48 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+
+declare_lint!(ARM_PATHS, Warn, "Report the number of paths mentioned by match arms");
+
+#[derive(Default)]
+struct Pass {
+    paths: Vec<usize>,
+}
+
+impl_lint_pass!(Pass => [ARM_PATHS]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_arm(&mut self, _: &LateContext<'tcx>, _: &'tcx hir::Arm<'tcx>) {
+        self.paths.push(0);
+    }
+
+    fn check_expr(&mut self, _: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        if let (hir::ExprKind::Path(_), Some(paths)) = (&expr.kind, self.paths.last_mut()) {
+            *paths += 1;
+        }
+    }
+
+    fn check_arm_post(&mut self, cx: &LateContext<'tcx>, arm: &'tcx hir::Arm<'tcx>) {
+        let paths = self.paths.pop().unwrap();
+        cx.struct_span_lint(ARM_PATHS, arm.span, format!("arm mentions {paths} paths"), |lint| {
+            lint
+        });
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&ARM_PATHS]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass::default()));
+}
@@ -0,0 +1,49 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_ast;
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_ast as ast;
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+
+declare_lint!(ATTRIBUTES, Warn, "Report the attributes of each node");
+
+declare_lint_pass!(Pass => [ATTRIBUTES]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_attributes(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        attrs: &'tcx [ast::Attribute],
+        hir_id: hir::HirId,
+    ) {
+        // Skip the attributes of the injected `extern crate std` and prelude import.
+        if attrs.iter().all(|attr| attr.span.from_expansion()) {
+            return;
+        }
+        let names =
+            attrs.iter().map(|attr| format!("`{}`", attr.name_or_empty())).collect::<Vec<_>>();
+        let kind = if hir_id == hir::CRATE_HIR_ID { "crate-level attributes" } else { "attributes" };
+        cx.struct_span_lint(
+            ATTRIBUTES,
+            attrs[0].span,
+            format!("{kind}: {}", names.join(", ")),
+            |lint| lint,
+        );
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&ATTRIBUTES]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
@@ -0,0 +1,43 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+
+declare_lint!(CLOSURES, Warn, "Report closures along with the number of arguments they take");
+
+declare_lint_pass!(Pass => [CLOSURES]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_closure(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        closure: &'tcx hir::Closure<'tcx>,
+        hir_id: hir::HirId,
+    ) {
+        let capture = match closure.capture_clause {
+            hir::CaptureBy::Value => "`move` closure",
+            hir::CaptureBy::Ref => "closure",
+        };
+        let inputs = closure.fn_decl.inputs.len();
+        let span = cx.tcx.hir().span(hir_id);
+        cx.struct_span_lint(CLOSURES, span, format!("{capture} taking {inputs} arguments"), |lint| {
+            lint
+        });
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&CLOSURES]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
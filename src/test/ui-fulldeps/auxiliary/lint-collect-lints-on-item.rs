@@ -0,0 +1,72 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_errors;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_errors::DiagnosticMessage;
+use rustc_hir as hir;
+use rustc_lint::{collect_late_lints_on_item, LateContext, LateLintPass, LintContext};
+
+declare_lint!(ITEM_FNS, Warn, "Report the functions of the previewed items");
+declare_lint!(PREVIEWS, Warn, "Report the lints collected when previewing an item");
+
+declare_lint_pass!(FnNames => [ITEM_FNS]);
+
+impl<'tcx> LateLintPass<'tcx> for FnNames {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        if let hir::ItemKind::Fn(..) = item.kind {
+            cx.struct_span_lint(
+                ITEM_FNS,
+                item.ident.span,
+                format!("function `{}`", item.ident),
+                |lint| lint,
+            );
+        }
+    }
+}
+
+declare_lint_pass!(Pass => [PREVIEWS]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        for &item_id in cx.tcx.hir().root_module().item_ids {
+            let item = cx.tcx.hir().item(item_id);
+            if !item.ident.as_str().starts_with("previewed_") {
+                continue;
+            }
+            let (_, lints) = collect_late_lints_on_item(cx.tcx, item.owner_id.def_id, FnNames);
+            for lint in &lints {
+                let msg = match &lint.message[0].0 {
+                    DiagnosticMessage::Str(msg) | DiagnosticMessage::Eager(msg) => msg,
+                    _ => unreachable!(),
+                };
+                cx.struct_span_lint(
+                    PREVIEWS,
+                    lint.span.primary_span().unwrap(),
+                    format!("previewed lint: {msg}"),
+                    |lint| lint,
+                );
+            }
+            cx.struct_span_lint(
+                PREVIEWS,
+                item.ident.span,
+                format!("previewing `{}` collected {} lints", item.ident, lints.len()),
+                |lint| lint,
+            );
+        }
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&ITEM_FNS, &PREVIEWS]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
@@ -0,0 +1,47 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_middle;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::print::with_no_trimmed_paths;
+
+declare_lint!(LITERAL_BODIES, Warn, "Report the bodies each literal is nested in");
+
+declare_lint_pass!(Pass => [LITERAL_BODIES]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        if let hir::ExprKind::Lit(_) = expr.kind {
+            let bodies = cx
+                .enclosing_body_stack()
+                .iter()
+                .map(|&body_id| {
+                    let owner = cx.tcx.hir().body_owner_def_id(body_id);
+                    format!("`{}`", with_no_trimmed_paths!(cx.tcx.def_path_str(owner.to_def_id())))
+                })
+                .collect::<Vec<_>>();
+            cx.struct_span_lint(
+                LITERAL_BODIES,
+                expr.span,
+                format!("literal in the bodies of {}", bodies.join(", ")),
+                |lint| lint,
+            );
+        }
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&LITERAL_BODIES]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
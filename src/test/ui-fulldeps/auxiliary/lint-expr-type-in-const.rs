@@ -0,0 +1,38 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+
+declare_lint!(BINARY_EXPR_TYPES, Warn, "Report the type of binary expressions");
+
+declare_lint_pass!(Pass => [BINARY_EXPR_TYPES]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_expr_post(&mut self, cx: &LateContext<'tcx>, e: &'tcx hir::Expr<'tcx>) {
+        if let hir::ExprKind::Binary(..) = e.kind {
+            let ty = cx.typeck_results().expr_ty(e);
+            cx.struct_span_lint(
+                BINARY_EXPR_TYPES,
+                e.span,
+                format!("binary expression of type `{ty}`"),
+                |lint| lint,
+            );
+        }
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&BINARY_EXPR_TYPES]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
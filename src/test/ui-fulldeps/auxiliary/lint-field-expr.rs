@@ -0,0 +1,44 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+extern crate rustc_span;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::symbol::Ident;
+
+declare_lint!(FIELD_EXPRS, Warn, "Report field accesses along with the type of their base");
+
+declare_lint_pass!(Pass => [FIELD_EXPRS]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_field_expr(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        expr: &'tcx hir::Expr<'tcx>,
+        base: &'tcx hir::Expr<'tcx>,
+        field: Ident,
+    ) {
+        let base_ty = cx.typeck_results().expr_ty(base);
+        cx.struct_span_lint(
+            FIELD_EXPRS,
+            expr.span,
+            format!("access to field `{field}` of `{base_ty}`"),
+            |lint| lint,
+        );
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&FIELD_EXPRS]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
@@ -0,0 +1,38 @@
+// force-host
+
+#![feature(let_chains, rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+
+declare_lint!(GENERIC_ARGS, Warn, "Report the kind of each generic argument");
+
+declare_lint_pass!(Pass => [GENERIC_ARGS]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_generic_arg(&mut self, cx: &LateContext<'tcx>, arg: &'tcx hir::GenericArg<'tcx>) {
+        if let hir::GenericArg::Lifetime(lifetime) = arg && lifetime.is_elided() {
+            return;
+        }
+        cx.struct_span_lint(
+            GENERIC_ARGS,
+            arg.span(),
+            format!("{} argument", arg.descr()),
+            |lint| lint,
+        );
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&GENERIC_ARGS]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
@@ -0,0 +1,49 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+
+declare_lint!(GENERIC_ARGS, Warn, "Report the kinds of generic arguments of path segments");
+
+declare_lint_pass!(Pass => [GENERIC_ARGS]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_generic_args(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        args: &'tcx hir::GenericArgs<'tcx>,
+        _: hir::HirId,
+    ) {
+        let (mut lifetimes, mut types, mut consts) = (0, 0, 0);
+        for arg in args.args {
+            match arg {
+                hir::GenericArg::Lifetime(_) => lifetimes += 1,
+                hir::GenericArg::Type(_) => types += 1,
+                hir::GenericArg::Const(_) => consts += 1,
+                hir::GenericArg::Infer(_) => {}
+            }
+        }
+        cx.struct_span_lint(
+            GENERIC_ARGS,
+            args.span_ext,
+            format!("{lifetimes} lifetime, {types} type and {consts} const arguments"),
+            |lint| lint,
+        );
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&GENERIC_ARGS]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
@@ -0,0 +1,60 @@
+// force-host
+
+#![feature(let_chains, rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+
+declare_lint!(ASM_OPERANDS, Warn, "Report the operands of inline assembly");
+
+declare_lint_pass!(Pass => [ASM_OPERANDS]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_inline_asm(&mut self, cx: &LateContext<'tcx>, asm: &'tcx hir::InlineAsm<'tcx>) {
+        for &(ref op, span) in asm.operands {
+            let kind = match op {
+                hir::InlineAsmOperand::In { .. } => "in",
+                hir::InlineAsmOperand::Out { .. } => "out",
+                hir::InlineAsmOperand::InOut { .. }
+                | hir::InlineAsmOperand::SplitInOut { .. } => "inout",
+                hir::InlineAsmOperand::Const { .. } => "const",
+                hir::InlineAsmOperand::SymFn { .. }
+                | hir::InlineAsmOperand::SymStatic { .. } => "sym",
+            };
+            cx.struct_span_lint(
+                ASM_OPERANDS,
+                span,
+                format!("`{kind}` operand of inline assembly"),
+                |lint| lint,
+            );
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        if let hir::ExprKind::Lit(_) = expr.kind
+            && let Some(parent) = cx.parent_expr()
+            && let hir::ExprKind::InlineAsm(_) = parent.kind
+        {
+            cx.struct_span_lint(
+                ASM_OPERANDS,
+                expr.span,
+                "operand expression of inline assembly",
+                |lint| lint,
+            );
+        }
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&ASM_OPERANDS]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
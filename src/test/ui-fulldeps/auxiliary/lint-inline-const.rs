@@ -0,0 +1,34 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+
+declare_lint!(INLINE_CONSTS, Warn, "Report inline const blocks along with their type");
+
+declare_lint_pass!(Pass => [INLINE_CONSTS]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_inline_const(&mut self, cx: &LateContext<'tcx>, anon_const: &'tcx hir::AnonConst) {
+        let ty = cx.tcx.type_of(anon_const.def_id);
+        let span = cx.tcx.hir().body(anon_const.body).value.span;
+        cx.struct_span_lint(INLINE_CONSTS, span, format!("inline const of type `{ty}`"), |lint| {
+            lint
+        });
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&INLINE_CONSTS]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
@@ -0,0 +1,83 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+extern crate rustc_span;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::symbol::{Ident, Symbol};
+
+declare_lint!(ITEM_ENDS, Warn, "Report the end of each item, along with the items it is nested in");
+
+#[derive(Default)]
+struct Pass {
+    names: Vec<Symbol>,
+}
+
+impl_lint_pass!(Pass => [ITEM_ENDS]);
+
+impl Pass {
+    fn exit(&mut self, cx: &LateContext<'_>, kind: &str, ident: Ident) {
+        let path = self
+            .names
+            .iter()
+            .filter(|name| !name.is_empty())
+            .map(|name| name.as_str())
+            .collect::<Vec<_>>()
+            .join("::");
+        self.names.pop();
+        if !ident.name.is_empty() {
+            cx.struct_span_lint(ITEM_ENDS, ident.span, format!("end of {kind} `{path}`"), |lint| {
+                lint
+            });
+        }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_item(&mut self, _: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        if !item.span.from_expansion() {
+            self.names.push(item.ident.name);
+        }
+    }
+
+    fn check_item_post(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        if !item.span.from_expansion() {
+            self.exit(cx, "item", item.ident);
+        }
+    }
+
+    fn check_trait_item(&mut self, _: &LateContext<'tcx>, item: &'tcx hir::TraitItem<'tcx>) {
+        self.names.push(item.ident.name);
+    }
+
+    fn check_trait_item_post(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::TraitItem<'tcx>) {
+        self.exit(cx, "trait item", item.ident);
+    }
+
+    fn check_foreign_item(&mut self, _: &LateContext<'tcx>, item: &'tcx hir::ForeignItem<'tcx>) {
+        self.names.push(item.ident.name);
+    }
+
+    fn check_foreign_item_post(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        item: &'tcx hir::ForeignItem<'tcx>,
+    ) {
+        self.exit(cx, "foreign item", item.ident);
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&ITEM_ENDS]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass::default()));
+}
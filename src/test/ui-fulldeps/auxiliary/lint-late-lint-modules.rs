@@ -0,0 +1,64 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{late_lint_modules, LateContext, LateLintPass, LintContext};
+
+declare_lint!(MODULE_FNS, Warn, "Report the functions of the modules linted on demand");
+
+struct FnNames {
+    pass: usize,
+}
+
+impl_lint_pass!(FnNames => [MODULE_FNS]);
+
+impl<'tcx> LateLintPass<'tcx> for FnNames {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        if let hir::ItemKind::Fn(..) = item.kind {
+            cx.struct_span_lint(
+                MODULE_FNS,
+                item.ident.span,
+                format!("function `{}` checked by pass #{}", item.ident, self.pass),
+                |lint| lint,
+            );
+        }
+    }
+}
+
+declare_lint_pass!(Pass => []);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        // The modules at the crate root, in the reverse order.
+        let modules = cx
+            .tcx
+            .hir()
+            .root_module()
+            .item_ids
+            .iter()
+            .rev()
+            .filter(|item_id| matches!(cx.tcx.hir().item(**item_id).kind, hir::ItemKind::Mod(_)))
+            .map(|item_id| item_id.owner_id.def_id)
+            .collect::<Vec<_>>();
+        let mut passes = 0;
+        late_lint_modules(cx.tcx, &modules, || {
+            passes += 1;
+            FnNames { pass: passes }
+        });
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&MODULE_FNS]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
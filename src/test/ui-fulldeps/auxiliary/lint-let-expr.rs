@@ -0,0 +1,30 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+
+declare_lint!(LET_EXPRS, Warn, "Report `let` expressions");
+
+declare_lint_pass!(Pass => [LET_EXPRS]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_let_expr(&mut self, cx: &LateContext<'tcx>, lex: &'tcx hir::Let<'tcx>) {
+        cx.struct_span_lint(LET_EXPRS, lex.span, "`let` expression", |lint| lint);
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&LET_EXPRS]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
@@ -0,0 +1,46 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_middle;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::LintLevelSource;
+use rustc_session::lint::builtin::UNUSED_VARIABLES;
+
+declare_lint!(LEVELS, Warn, "Report the level of `unused_variables` at each function");
+
+declare_lint_pass!(Pass => [LEVELS]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        if let hir::ItemKind::Fn(..) = item.kind {
+            let (level, source) = cx.lint_level_at_node(UNUSED_VARIABLES);
+            let source = match source {
+                LintLevelSource::Default => "by default".to_string(),
+                LintLevelSource::Node { name, .. } => format!("by `{name}`"),
+                LintLevelSource::CommandLine(name, _) => format!("by `{name}` on the command line"),
+            };
+            cx.struct_span_lint(
+                LEVELS,
+                item.ident.span,
+                format!("`unused_variables` is `{}` here, {source}", level.as_str()),
+                |lint| lint,
+            );
+        }
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&LEVELS]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
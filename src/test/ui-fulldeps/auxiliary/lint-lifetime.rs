@@ -0,0 +1,38 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+
+declare_lint!(LIFETIMES, Warn, "Report the lifetimes which aren't elided");
+
+declare_lint_pass!(Pass => [LIFETIMES]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_lifetime(&mut self, cx: &LateContext<'tcx>, lifetime: &'tcx hir::Lifetime) {
+        if lifetime.is_elided() {
+            return;
+        }
+        cx.struct_span_lint(
+            LIFETIMES,
+            lifetime.ident.span,
+            format!("lifetime `{}`", lifetime.ident),
+            |lint| lint,
+        );
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&LIFETIMES]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
@@ -0,0 +1,54 @@
+// force-host
+
+#![feature(let_chains, rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_middle;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_hir::def_id::CRATE_DEF_ID;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use std::collections::BTreeMap;
+
+declare_lint!(FNS_PER_MODULE, Warn, "Report the number of functions in each module");
+
+declare_lint_pass!(Pass => [FNS_PER_MODULE]);
+
+/// The number of functions in each module, by module path.
+#[derive(Default)]
+struct FnCounts(BTreeMap<String, usize>);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        let module = cx.tcx.parent_module(item.hir_id());
+        // Only count the functions in the modules below the crate root.
+        if let hir::ItemKind::Fn(..) = item.kind && module != CRATE_DEF_ID {
+            let path = with_no_trimmed_paths!(cx.tcx.def_path_str(module.to_def_id()));
+            cx.with_module_pass_state(|counts: &mut FnCounts| {
+                *counts.0.entry(path).or_default() += 1;
+            });
+        }
+    }
+
+    fn finalize(&mut self, cx: &LateContext<'tcx>) {
+        let counts = cx.with_module_pass_state(|counts: &mut FnCounts| {
+            counts.0.iter().map(|(path, count)| format!("`{path}`: {count}")).collect::<Vec<_>>()
+        });
+        cx.lint(FNS_PER_MODULE, format!("functions per module: {}", counts.join(", ")), |lint| {
+            lint
+        });
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&FNS_PER_MODULE]);
+    reg.lint_store.register_late_mod_pass(|_| Box::new(Pass));
+}
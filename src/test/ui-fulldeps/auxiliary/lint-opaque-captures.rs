@@ -0,0 +1,37 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_hir::def_id::DefId;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+
+declare_lint!(OPAQUE_CAPTURES, Warn, "Report the lifetimes captured by `impl Trait` types");
+
+declare_lint_pass!(Pass => [OPAQUE_CAPTURES]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_opaque_captured_arg(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        arg: &'tcx hir::GenericArg<'tcx>,
+        def_id: DefId,
+    ) {
+        let name = cx.tcx.item_name(def_id);
+        cx.struct_span_lint(OPAQUE_CAPTURES, arg.span(), format!("captures `{name}`"), |lint| lint);
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&OPAQUE_CAPTURES]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
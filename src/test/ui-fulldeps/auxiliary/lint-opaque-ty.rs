@@ -0,0 +1,41 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_hir::def_id::LocalDefId;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+
+declare_lint!(OPAQUE_TYS, Warn, "Report opaque types along with the number of their bounds");
+
+declare_lint_pass!(Pass => [OPAQUE_TYS]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_opaque_ty(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        opaque: &'tcx hir::OpaqueTy<'tcx>,
+        def_id: LocalDefId,
+    ) {
+        cx.struct_span_lint(
+            OPAQUE_TYS,
+            cx.tcx.def_span(def_id),
+            format!("opaque type with {} bound(s)", opaque.bounds.len()),
+            |lint| lint,
+        );
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&OPAQUE_TYS]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
@@ -0,0 +1,44 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+extern crate rustc_span;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::sym;
+
+declare_lint!(LEN_CALLS, Warn, "Report `len` calls along with the expression they are nested in");
+
+declare_lint_pass!(Pass => [LEN_CALLS]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let hir::ExprKind::MethodCall(segment, ..) = expr.kind else { return };
+        if segment.ident.name != sym::len {
+            return;
+        }
+        let parent = match cx.parent_expr().map(|parent| &parent.kind) {
+            Some(hir::ExprKind::Call(..)) => "nested in a call",
+            Some(hir::ExprKind::MethodCall(..)) => "nested in a method call",
+            Some(hir::ExprKind::Closure(..)) => "nested in a closure",
+            Some(hir::ExprKind::Block(..)) => "nested in a block",
+            Some(_) => "nested in another expression",
+            None => "outside of other expressions",
+        };
+        cx.struct_span_lint(LEN_CALLS, expr.span, format!("`len` call {parent}"), |lint| lint);
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&LEN_CALLS]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
@@ -0,0 +1,36 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+
+declare_lint!(PAT_FIELDS, Warn, "Report the fields of struct patterns");
+
+declare_lint_pass!(Pass => [PAT_FIELDS]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_pat_field(&mut self, cx: &LateContext<'tcx>, field: &'tcx hir::PatField<'tcx>) {
+        let shorthand = if field.is_shorthand { " in shorthand form" } else { "" };
+        cx.struct_span_lint(
+            PAT_FIELDS,
+            field.span,
+            format!("pattern field `{}`{shorthand}", field.ident),
+            |lint| lint,
+        );
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&PAT_FIELDS]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
@@ -0,0 +1,51 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+
+declare_lint!(PATH_DEPTHS, Warn, "Report how deeply each path is nested in other paths");
+
+#[derive(Default)]
+struct Pass {
+    depth: usize,
+}
+
+impl_lint_pass!(Pass => [PATH_DEPTHS]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_path(&mut self, _: &LateContext<'tcx>, path: &hir::Path<'tcx>, _: hir::HirId) {
+        if !path.span.from_expansion() {
+            self.depth += 1;
+        }
+    }
+
+    fn check_path_post(&mut self, cx: &LateContext<'tcx>, path: &hir::Path<'tcx>, _: hir::HirId) {
+        if path.span.from_expansion() {
+            return;
+        }
+        self.depth -= 1;
+        let ident = path.segments.last().unwrap().ident;
+        cx.struct_span_lint(
+            PATH_DEPTHS,
+            ident.span,
+            format!("end of path `{ident}` nested {} deep", self.depth),
+            |lint| lint,
+        );
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&PATH_DEPTHS]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass::default()));
+}
@@ -0,0 +1,43 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+extern crate rustc_span;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::Span;
+
+declare_lint!(QPATHS, Warn, "Report the paths which are qualified or type-relative");
+
+declare_lint_pass!(Pass => [QPATHS]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_qpath(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        qpath: &'tcx hir::QPath<'tcx>,
+        _: hir::HirId,
+        span: Span,
+    ) {
+        let msg = match qpath {
+            hir::QPath::Resolved(Some(_), _) => "qualified path",
+            hir::QPath::TypeRelative(..) => "type-relative path",
+            hir::QPath::Resolved(None, _) | hir::QPath::LangItem(..) => return,
+        };
+        cx.struct_span_lint(QPATHS, span, msg, |lint| lint);
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&QPATHS]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
@@ -0,0 +1,66 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_hir_analysis;
+extern crate rustc_middle;
+extern crate rustc_span;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir_analysis::relate_substs_by_variance;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty;
+use rustc_span::Span;
+
+declare_lint!(
+    VARIANCES,
+    Warn,
+    "Report the variances relating the two parameter types of a function"
+);
+
+declare_lint_pass!(Pass => [VARIANCES]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        _: FnKind<'tcx>,
+        decl: &'tcx hir::FnDecl<'tcx>,
+        _: &'tcx hir::Body<'tcx>,
+        _: Span,
+        def_id: hir::def_id::LocalDefId,
+    ) {
+        let inputs = cx.tcx.fn_sig(def_id).skip_binder().inputs();
+        let [a, b] = inputs else { return };
+        let (def_id, substs_a, substs_b) = match (a.peel_refs().kind(), b.peel_refs().kind()) {
+            (ty::Adt(a, substs_a), ty::Adt(_, substs_b)) => (a.did(), substs_a, substs_b),
+            (ty::Dynamic(a, ..), ty::Dynamic(b, ..)) => {
+                let (Some(a), Some(b)) = (a.principal(), b.principal()) else { return };
+                let (a, b) = (a.skip_binder(), b.skip_binder());
+                (a.def_id, a.substs, b.substs)
+            }
+            _ => return,
+        };
+        let msg = match relate_substs_by_variance(cx.tcx, def_id, substs_a, substs_b) {
+            Some(variances) => format!("`{a}` and `{b}` are related by {variances:?}"),
+            None => format!("`{}` has no variances", cx.tcx.def_path_str(def_id)),
+        };
+        cx.struct_span_lint(VARIANCES, decl.inputs[0].span.to(decl.inputs[1].span), msg, |lint| {
+            lint
+        });
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&VARIANCES]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
@@ -0,0 +1,63 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{run_late_pass_on_item, LateContext, LateLintPass, LintContext};
+
+declare_lint!(ITEM_FNS, Warn, "Report the functions the pass run on an item visits");
+declare_lint!(PASS_RUNS, Warn, "Report how many functions the pass run on an item visited");
+
+#[derive(Default)]
+struct FnNames {
+    fns: usize,
+}
+
+impl_lint_pass!(FnNames => [ITEM_FNS]);
+
+impl<'tcx> LateLintPass<'tcx> for FnNames {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        if let hir::ItemKind::Fn(..) = item.kind {
+            self.fns += 1;
+            cx.struct_span_lint(
+                ITEM_FNS,
+                item.ident.span,
+                format!("function `{}`", item.ident),
+                |lint| lint,
+            );
+        }
+    }
+}
+
+declare_lint_pass!(Pass => [PASS_RUNS]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        for &item_id in cx.tcx.hir().root_module().item_ids {
+            let item = cx.tcx.hir().item(item_id);
+            if item.ident.as_str().starts_with("checked_") {
+                let pass = run_late_pass_on_item(cx.tcx, item.owner_id.def_id, FnNames::default());
+                cx.struct_span_lint(
+                    PASS_RUNS,
+                    item.ident.span,
+                    format!("the pass run on `{}` visited {} functions", item.ident, pass.fns),
+                    |lint| lint,
+                );
+            }
+        }
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&ITEM_FNS, &PASS_RUNS]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
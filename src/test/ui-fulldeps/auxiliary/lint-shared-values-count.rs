@@ -0,0 +1,62 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+declare_lint!(ADJUSTED_TYPE_LOOKUPS, Warn, "Report how often adjusted types are looked up");
+
+declare_lint_pass!(Producer => [ADJUSTED_TYPE_LOOKUPS]);
+declare_lint_pass!(Consumer => []);
+
+/// The number of adjusted types looked up in the typeck results, and read from the
+/// values shared by `Producer`.
+static LOOKUPS: AtomicUsize = AtomicUsize::new(0);
+static READS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Clone)]
+struct AdjustedTy(String);
+
+impl<'tcx> LateLintPass<'tcx> for Producer {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        LOOKUPS.fetch_add(1, Ordering::Relaxed);
+        let ty = cx.typeck_results().expr_ty_adjusted(expr);
+        cx.share_value(expr.hir_id, AdjustedTy(ty.to_string()));
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        let lookups = LOOKUPS.load(Ordering::Relaxed);
+        let reads = READS.load(Ordering::Relaxed);
+        cx.lint(
+            ADJUSTED_TYPE_LOOKUPS,
+            format!("{lookups} adjusted types looked up for {reads} reads"),
+            |lint| lint,
+        );
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for Consumer {
+    fn check_expr_post(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let AdjustedTy(_) = cx.shared_value(expr.hir_id).unwrap();
+        READS.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&ADJUSTED_TYPE_LOOKUPS]);
+    reg.lint_store.register_late_pass(|_| Box::new(Producer));
+    reg.lint_store.register_late_pass(|_| Box::new(Consumer));
+    reg.lint_store.register_late_pass(|_| Box::new(Consumer));
+    reg.lint_store.register_late_pass(|_| Box::new(Consumer));
+}
@@ -0,0 +1,52 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+
+declare_lint!(ADJUSTED_METHOD_CALLS, Warn, "Report method calls along with their adjusted type");
+
+declare_lint_pass!(Producer => []);
+declare_lint_pass!(Consumer => [ADJUSTED_METHOD_CALLS]);
+
+#[derive(Clone)]
+struct AdjustedTy(String);
+
+impl<'tcx> LateLintPass<'tcx> for Producer {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let ty = cx.typeck_results().expr_ty_adjusted(expr);
+        cx.share_value(expr.hir_id, AdjustedTy(ty.to_string()));
+    }
+}
+
+// The values are read in `check_expr_post`, which runs once all passes ran `check_expr`,
+// so this doesn't depend on the order the passes are registered in.
+impl<'tcx> LateLintPass<'tcx> for Consumer {
+    fn check_expr_post(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        if let hir::ExprKind::MethodCall(..) = expr.kind {
+            let AdjustedTy(ty) = cx.shared_value(expr.hir_id).unwrap();
+            cx.struct_span_lint(
+                ADJUSTED_METHOD_CALLS,
+                expr.span,
+                format!("method call of adjusted type `{ty}`"),
+                |lint| lint,
+            );
+        }
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&ADJUSTED_METHOD_CALLS]);
+    reg.lint_store.register_late_pass(|_| Box::new(Consumer));
+    reg.lint_store.register_late_pass(|_| Box::new(Producer));
+}
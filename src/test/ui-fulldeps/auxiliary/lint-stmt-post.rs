@@ -0,0 +1,32 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+
+declare_lint!(LET_STMT_POST, Warn, "Report `let` statements after checking their contents");
+
+declare_lint_pass!(Pass => [LET_STMT_POST]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_stmt_post(&mut self, cx: &LateContext<'tcx>, s: &'tcx hir::Stmt<'tcx>) {
+        if let hir::StmtKind::Local(_) = s.kind {
+            cx.struct_span_lint(LET_STMT_POST, s.span, "checked `let` statement", |lint| lint);
+        }
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&LET_STMT_POST]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
@@ -0,0 +1,59 @@
+// force-host
+
+#![feature(if_let_guard, rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_hir::def::{DefKind, Res};
+
+declare_lint!(BODY_TYPES, Warn, "Report the type of the value of some bodies");
+
+declare_lint_pass!(Pass => [BODY_TYPES]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        // Outside of any body.
+        if let hir::ItemKind::Const(_, body_id) = item.kind {
+            let value = cx.tcx.hir().body(body_id).value;
+            let ty = cx.typeck_results_for(body_id).expr_ty(value);
+            cx.struct_span_lint(
+                BODY_TYPES,
+                value.span,
+                format!("initializer of type `{ty}`"),
+                |lint| lint,
+            );
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let (body_id, msg) = match expr.kind {
+            // The body of the closure, which is not the current body yet.
+            hir::ExprKind::Closure(closure) => (closure.body, "closure body"),
+            // The body of another item.
+            hir::ExprKind::Path(ref qpath) => match cx.qpath_res(qpath, expr.hir_id) {
+                Res::Def(DefKind::Const, def_id) if let Some(def_id) = def_id.as_local() => {
+                    (cx.tcx.hir().body_owned_by(def_id), "constant initializer")
+                }
+                _ => return,
+            },
+            _ => return,
+        };
+        let value = cx.tcx.hir().body(body_id).value;
+        let ty = cx.typeck_results_for(body_id).expr_ty(value);
+        cx.struct_span_lint(BODY_TYPES, expr.span, format!("{msg} of type `{ty}`"), |lint| lint);
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&BODY_TYPES]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
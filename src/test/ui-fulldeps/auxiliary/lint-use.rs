@@ -0,0 +1,52 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_middle;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::print::with_no_trimmed_paths;
+
+declare_lint!(USES, Warn, "Report imports along with what they resolve to");
+
+declare_lint_pass!(Pass => [USES]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_use(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        path: &'tcx hir::UsePath<'tcx>,
+        kind: hir::UseKind,
+    ) {
+        // Skip the injected prelude import.
+        if path.span.from_expansion() {
+            return;
+        }
+        let defs: Vec<_> = path
+            .res
+            .iter()
+            .filter_map(|res| res.opt_def_id())
+            .map(|def_id| format!("`{}`", with_no_trimmed_paths!(cx.tcx.def_path_str(def_id))))
+            .collect();
+        cx.struct_span_lint(
+            USES,
+            path.span,
+            format!("{kind:?} import of {}", defs.join(", ")),
+            |lint| lint,
+        );
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&USES]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass));
+}
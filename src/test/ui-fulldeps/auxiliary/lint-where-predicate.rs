@@ -0,0 +1,41 @@
+// force-host
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+#[macro_use]
+extern crate rustc_lint;
+#[macro_use]
+extern crate rustc_session;
+
+use rustc_driver::plugin::Registry;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+
+declare_lint!(WHERE_PREDICATES, Warn, "Count the where predicates seen by late lints");
+
+#[derive(Default)]
+struct Pass {
+    count: usize,
+}
+
+impl_lint_pass!(Pass => [WHERE_PREDICATES]);
+
+impl<'tcx> LateLintPass<'tcx> for Pass {
+    fn check_where_predicate(&mut self, cx: &LateContext<'tcx>, p: &'tcx hir::WherePredicate<'tcx>) {
+        self.count += 1;
+        cx.struct_span_lint(
+            WHERE_PREDICATES,
+            p.span(),
+            format!("where predicate #{}", self.count),
+            |lint| lint,
+        );
+    }
+}
+
+#[no_mangle]
+fn __rustc_plugin_registrar(reg: &mut Registry) {
+    reg.lint_store.register_lints(&[&WHERE_PREDICATES]);
+    reg.lint_store.register_late_pass(|_| Box::new(Pass::default()));
+}
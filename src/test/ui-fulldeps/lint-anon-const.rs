@@ -0,0 +1,25 @@
+// check-pass
+// aux-build:lint-anon-const.rs
+// ignore-stage1
+
+// Check that `check_anon_const` is called for array lengths, enum discriminants and
+// const arguments, with the typeck results of the anonymous constant.
+
+#![feature(plugin)]
+#![plugin(lint_anon_const)]
+//~^ WARN use of deprecated attribute `plugin`
+
+struct S<const N: usize>;
+
+enum E {
+    A = 1, //~ WARN anonymous constant of type `isize`
+}
+
+fn main() {
+    let _ = [0u8; 3]; //~ WARN anonymous constant of type `usize`
+    let _: [u8; 2 + 1] = [0; 3];
+    //~^ WARN anonymous constant of type `usize`
+    //~| WARN anonymous constant of type `usize`
+    let _: S<4> = S; //~ WARN anonymous constant of type `usize`
+    let _ = E::A;
+}
@@ -0,0 +1,17 @@
+// check-pass
+// aux-build:lint-arm-post.rs
+// ignore-stage1
+
+// Check that `check_arm_post` is called after the pattern, guard and body of an arm.
+
+#![feature(plugin)]
+#![plugin(lint_arm_post)]
+//~^ WARN use of deprecated attribute `plugin`
+
+fn main() {
+    let y = 1;
+    let _ = match Some(2) {
+        Some(x) if x > 0 => x + y, //~ WARN arm mentions 3 paths
+        _ => 0, //~ WARN arm mentions 0 paths
+    };
+}
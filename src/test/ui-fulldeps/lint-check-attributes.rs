@@ -0,0 +1,24 @@
+// check-pass
+// aux-build:lint-check-attributes.rs
+// ignore-stage1
+
+// Check that `check_attributes` is called once with all the attributes of each node,
+// and with the `HirId` of the node, which tells crate-level attributes apart.
+
+#![feature(plugin)]
+//~^ WARN crate-level attributes: `feature`, `plugin`
+#![plugin(lint_check_attributes)]
+//~^ WARN use of deprecated attribute `plugin`
+
+#[inline]
+#[must_use]
+fn f() -> u8 {
+    //~^^^ WARN attributes: `inline`, `must_use`
+    0
+}
+
+fn main() {
+    #[allow(unused_variables)]
+    //~^ WARN attributes: `allow`
+    let x = f();
+}
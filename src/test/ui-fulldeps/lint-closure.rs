@@ -0,0 +1,15 @@
+// check-pass
+// aux-build:lint-closure.rs
+// ignore-stage1
+
+// Check that `check_closure` is called for closures, with their `HirId`.
+
+#![feature(plugin)]
+#![plugin(lint_closure)]
+//~^ WARN use of deprecated attribute `plugin`
+
+fn main() {
+    let a = 1;
+    let _ = |x: u8| x; //~ WARN closure taking 1 arguments
+    let _ = move || a; //~ WARN `move` closure taking 0 arguments
+}
@@ -0,0 +1,24 @@
+// check-pass
+// aux-build:lint-collect-lints-on-item.rs
+// ignore-stage1
+
+// Check that `collect_late_lints_on_item` returns the lints the pass emits on the item
+// instead of emitting them, leaving out the lints which are allowed.
+
+#![feature(plugin)]
+#![plugin(lint_collect_lints_on_item)]
+//~^ WARN use of deprecated attribute `plugin`
+
+fn previewed_a() {
+    //~^ WARN previewed lint: function `previewed_a`
+    //~| WARN previewing `previewed_a` collected 2 lints
+    fn inner() {} //~ WARN previewed lint: function `inner`
+    #[allow(item_fns)]
+    fn allowed() {}
+    inner();
+    allowed();
+}
+
+fn main() {
+    previewed_a();
+}
@@ -0,0 +1,26 @@
+// check-pass
+// aux-build:lint-enclosing-body-stack.rs
+// ignore-stage1
+
+// Check that `LateContext::enclosing_body_stack` holds the bodies of the closures and
+// anonymous constants the current node is nested in, but not those of the items the
+// current item is nested in.
+
+#![feature(plugin)]
+#![plugin(lint_enclosing_body_stack)]
+//~^ WARN use of deprecated attribute `plugin`
+
+fn main() {
+    let _ = 1; //~ WARN literal in the bodies of `main`
+    let _ = [0; 4];
+    //~^ WARN literal in the bodies of `main`
+    //~| WARN literal in the bodies of `main`, `main::{constant#0}`
+    let f = || {
+        let g = || 2;
+        //~^ WARN `main`, `main::{closure#0}`, `main::{closure#0}::{closure#0}`
+        g()
+    };
+    f();
+    const C: u8 = 3; //~ WARN literal in the bodies of `main::C`
+    let _ = C;
+}
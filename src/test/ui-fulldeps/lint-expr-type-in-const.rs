@@ -0,0 +1,19 @@
+// check-pass
+// aux-build:lint-expr-type-in-const.rs
+// ignore-stage1
+
+// Check that the typeck results are available to late lints for expressions
+// in `const` and `static` initializers.
+
+#![feature(plugin)]
+#![plugin(lint_expr_type_in_const)]
+//~^ WARN use of deprecated attribute `plugin`
+#![allow(dead_code)]
+
+const A: u32 = 1 + 2; //~ WARN binary expression of type `u32`
+
+static B: u64 = 3 * 4; //~ WARN binary expression of type `u64`
+
+const C: [u8; 2 + 3] = [0; 5]; //~ WARN binary expression of type `usize`
+
+fn main() {}
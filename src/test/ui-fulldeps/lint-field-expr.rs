@@ -0,0 +1,19 @@
+// check-pass
+// aux-build:lint-field-expr.rs
+// ignore-stage1
+
+// Check that `check_field_expr` is called for field accesses.
+
+#![feature(plugin)]
+#![plugin(lint_field_expr)]
+//~^ WARN use of deprecated attribute `plugin`
+
+struct Point {
+    x: u8,
+}
+
+fn main() {
+    let p = &Point { x: 1 };
+    let _ = p.x; //~ WARN access to field `x` of `&Point`
+    let _ = (2, 3).1; //~ WARN access to field `1` of `(i32, i32)`
+}
@@ -0,0 +1,24 @@
+// check-pass
+// aux-build:lint-generic-arg.rs
+// ignore-stage1
+
+// Check that `check_generic_arg` is called for each generic argument written in a
+// path, with its own span.
+
+#![feature(generic_arg_infer, plugin)]
+#![plugin(lint_generic_arg)]
+//~^ WARN use of deprecated attribute `plugin`
+#![allow(dead_code)]
+
+struct S<'a, T, const N: usize>(&'a T);
+
+fn f(_: S<'static, u8, 3>) {}
+//~^ WARN lifetime argument
+//~| WARN type argument
+//~| WARN constant argument
+
+fn main() {
+    let _: Vec<u8> = Vec::<_>::new();
+    //~^ WARN inferred argument
+    //~| WARN type argument
+}
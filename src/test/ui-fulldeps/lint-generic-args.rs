@@ -0,0 +1,19 @@
+// check-pass
+// aux-build:lint-generic-args.rs
+// ignore-stage1
+
+// Check that `check_generic_args` is called with all the kinds of generic arguments.
+
+#![feature(plugin)]
+#![plugin(lint_generic_args)]
+//~^ WARN use of deprecated attribute `plugin`
+
+struct Foo<'a, T, const N: usize>(&'a [T; N]);
+
+fn foo<'a>(_: Foo<'a, u8, 2>) {}
+//~^ WARN 1 lifetime, 1 type and 1 const arguments
+
+fn main() {
+    let _ = std::iter::empty::<u8>().count();
+    //~^ WARN 0 lifetime, 1 type and 0 const arguments
+}
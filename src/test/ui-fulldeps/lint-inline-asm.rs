@@ -0,0 +1,22 @@
+// check-pass
+// aux-build:lint-inline-asm.rs
+// ignore-stage1
+// only-x86_64
+
+// Check that `check_inline_asm` is called for inline assembly, before its operand
+// expressions are checked.
+
+#![feature(plugin)]
+#![plugin(lint_inline_asm)]
+//~^ WARN use of deprecated attribute `plugin`
+
+use std::arch::asm;
+
+fn main() {
+    unsafe {
+        asm!("/* {} {} */", in(reg) 0u32, out(reg) _);
+        //~^ WARN `in` operand of inline assembly
+        //~| WARN `out` operand of inline assembly
+        //~| WARN operand expression of inline assembly
+    }
+}
@@ -0,0 +1,18 @@
+// check-pass
+// aux-build:lint-inline-const.rs
+// ignore-stage1
+
+// Check that `check_inline_const` is called for inline const blocks, including
+// nested ones, but not for other anon consts such as array lengths.
+
+#![feature(plugin, inline_const)]
+#![plugin(lint_inline_const)]
+//~^ WARN use of deprecated attribute `plugin`
+
+fn main() {
+    let _: [u8; 2] = [0; 2];
+    let _ = const { 1u32 + 2 }; //~ WARN inline const of type `u32`
+    let _ = const { [const { 3u8 }; 4] };
+    //~^ WARN inline const of type `[u8; 4]`
+    //~| WARN inline const of type `u8`
+}
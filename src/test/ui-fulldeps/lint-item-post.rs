@@ -0,0 +1,28 @@
+// check-pass
+// aux-build:lint-item-post.rs
+// ignore-stage1
+
+// Check that `check_trait_item_post` and `check_foreign_item_post` are called after
+// the items nested in them, like `check_item_post`.
+
+#![feature(plugin)]
+#![plugin(lint_item_post)]
+//~^ WARN use of deprecated attribute `plugin`
+#![allow(dead_code)]
+
+trait Tr {
+    //~^ WARN end of item `Tr`
+    fn g() {
+        //~^ WARN end of trait item `Tr::g`
+        fn h() {} //~ WARN end of item `Tr::g::h`
+        h()
+    }
+    const C: u8; //~ WARN end of trait item `Tr::C`
+}
+
+extern "C" {
+    fn e(); //~ WARN end of foreign item `e`
+    static S: u8; //~ WARN end of foreign item `S`
+}
+
+fn main() {} //~ WARN end of item `main`
@@ -0,0 +1,28 @@
+// check-pass
+// aux-build:lint-late-lint-modules.rs
+// ignore-stage1
+
+// Check that `late_lint_modules` lints exactly the modules it is given, in the order
+// they are given, with a new pass for each of them.
+
+#![feature(plugin)]
+#![plugin(lint_late_lint_modules)]
+//~^ WARN use of deprecated attribute `plugin`
+
+mod a {
+    pub fn f() {} //~ WARN function `f` checked by pass #2
+}
+
+mod b {
+    pub fn g() {} //~ WARN function `g` checked by pass #1
+
+    pub mod c {
+        pub fn h() {}
+    }
+}
+
+fn main() {
+    a::f();
+    b::g();
+    b::c::h();
+}
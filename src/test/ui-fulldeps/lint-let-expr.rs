@@ -0,0 +1,20 @@
+// check-pass
+// aux-build:lint-let-expr.rs
+// ignore-stage1
+
+// Check that `check_let_expr` is called for `let` expressions, with the lint
+// attributes of the enclosing statement applied.
+
+#![feature(let_chains, plugin)]
+#![plugin(lint_let_expr)]
+//~^ WARN use of deprecated attribute `plugin`
+
+fn main() {
+    let x = Some(1);
+    if let Some(_y) = x {} //~ WARN `let` expression
+    while let Some(_) = None::<u8> {} //~ WARN `let` expression
+    if true && let Some(_z) = x {} //~ WARN `let` expression
+
+    #[allow(let_exprs)]
+    let _ = if let Some(_) = x { 1 } else { 0 };
+}
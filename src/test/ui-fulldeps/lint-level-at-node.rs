@@ -0,0 +1,27 @@
+// check-pass
+// aux-build:lint-level-at-node.rs
+// ignore-stage1
+
+// Check that `LateContext::lint_level_at_node` returns the level of another lint at
+// the node being checked, and where it was set.
+
+#![feature(plugin)]
+#![plugin(lint_level_at_node)]
+//~^ WARN use of deprecated attribute `plugin`
+
+fn a() {} //~ WARN `unused_variables` is `warn` here, by default
+
+#[deny(unused_variables)]
+fn b() {} //~ WARN `unused_variables` is `deny` here, by `unused_variables`
+
+#[allow(unused)]
+mod m {
+    pub fn c() {} //~ WARN `unused_variables` is `allow` here, by `unused`
+}
+
+fn main() {
+    //~^ WARN `unused_variables` is `warn` here, by default
+    a();
+    b();
+    m::c();
+}
@@ -0,0 +1,19 @@
+// check-pass
+// aux-build:lint-lifetime.rs
+// ignore-stage1
+
+// Check that `check_lifetime` is called for the lifetimes in types.
+
+#![feature(plugin)]
+#![plugin(lint_lifetime)]
+//~^ WARN use of deprecated attribute `plugin`
+
+fn f<'a>(x: &'a u8, _: &u8) -> &'a u8 {
+    //~^ WARN lifetime `'a`
+    //~| WARN lifetime `'a`
+    x
+}
+
+const S: &'static str = ""; //~ WARN lifetime `'static`
+
+fn main() {}
@@ -0,0 +1,23 @@
+// check-pass
+// aux-build:lint-module-pass-finalize.rs
+// compile-flags: -Z threads=2
+// ignore-stage1
+
+// Check that the module pass instances checking different modules, possibly in
+// parallel, accumulate their state for `finalize` with `with_module_pass_state`.
+
+#![feature(plugin)]
+#![plugin(lint_module_pass_finalize)]
+//~^ WARN use of deprecated attribute `plugin`
+#![allow(dead_code)]
+
+mod a {
+    fn f() {}
+    fn g() {}
+}
+
+mod b {
+    fn h() {}
+}
+
+fn main() {}
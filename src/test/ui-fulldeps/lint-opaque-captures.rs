@@ -0,0 +1,17 @@
+// check-pass
+// aux-build:lint-opaque-captures.rs
+// ignore-stage1
+
+// Check that late lints are told about the lifetimes captured by `impl Trait` types.
+
+#![feature(plugin)]
+#![plugin(lint_opaque_captures)]
+//~^ WARN use of deprecated attribute `plugin`
+
+fn two_lifetimes<'a, 'b>(x: &'a u8, _: &'b u8) -> impl Sized + 'a { x } //~ WARN captures `'a`
+
+fn one_lifetime<'a>(x: &'a u8) -> impl Sized + 'a { x } //~ WARN captures `'a`
+
+fn not_captured<'a>(_: &'a u8) -> impl Sized + 'static { 0 }
+
+fn main() {}
@@ -0,0 +1,22 @@
+// check-pass
+// aux-build:lint-opaque-ty.rs
+// ignore-stage1
+
+// Check that `check_opaque_ty` is called for each `impl Trait` type.
+
+#![feature(plugin)]
+#![plugin(lint_opaque_ty)]
+//~^ WARN use of deprecated attribute `plugin`
+
+fn one() -> impl Sized { //~ WARN opaque type with 1 bound(s)
+    0
+}
+
+fn two<'a>(x: &'a u8) -> impl Clone + 'a { //~ WARN opaque type with 2 bound(s)
+    x
+}
+
+fn main() {
+    one();
+    two(&0);
+}
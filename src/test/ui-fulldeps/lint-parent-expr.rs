@@ -0,0 +1,24 @@
+// check-pass
+// aux-build:lint-parent-expr.rs
+// ignore-stage1
+
+// Check that `LateContext::parent_expr` returns the innermost expression the
+// current one is nested in, without looking past the item being visited.
+
+#![feature(plugin)]
+#![plugin(lint_parent_expr)]
+//~^ WARN use of deprecated attribute `plugin`
+
+fn take(_: usize) {}
+
+fn main() {
+    let s = String::new();
+    take(s.len()); //~ WARN `len` call nested in a call
+    let _ = s.len().pow(2); //~ WARN `len` call nested in a method call
+    let _ = s.len(); //~ WARN `len` call nested in a block
+    let _ = || s.len(); //~ WARN `len` call nested in a closure
+    take({
+        const N: usize = "a".len(); //~ WARN `len` call outside of other expressions
+        N
+    });
+}
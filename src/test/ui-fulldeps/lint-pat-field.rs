@@ -0,0 +1,26 @@
+// check-pass
+// aux-build:lint-pat-field.rs
+// ignore-stage1
+
+// Check that `check_pat_field` is called for each field of struct patterns.
+
+#![feature(plugin)]
+#![plugin(lint_pat_field)]
+//~^ WARN use of deprecated attribute `plugin`
+#![allow(dead_code, unused_variables)]
+
+struct Named {
+    a: u8,
+    b: u8,
+}
+
+struct Tuple(u8, u8);
+
+fn main() {
+    let Named { a, b: _ } = Named { a: 0, b: 0 };
+    //~^ WARN pattern field `a` in shorthand form
+    //~| WARN pattern field `b`
+    let Tuple { 0: x, .. } = Tuple(0, 0);
+    //~^ WARN pattern field `0`
+    let Tuple(y, _) = Tuple(0, 0);
+}
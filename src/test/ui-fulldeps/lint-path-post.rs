@@ -0,0 +1,17 @@
+// check-pass
+// aux-build:lint-path-post.rs
+// ignore-stage1
+
+// Check that `check_path_post` is called after the generic arguments of a path, so
+// the paths nested in them end first.
+
+#![feature(plugin)]
+#![plugin(lint_path_post)]
+//~^ WARN use of deprecated attribute `plugin`
+
+fn main() {
+    let _: Vec<Option<u8>>;
+    //~^ WARN end of path `u8` nested 2 deep
+    //~| WARN end of path `Option` nested 1 deep
+    //~| WARN end of path `Vec` nested 0 deep
+}
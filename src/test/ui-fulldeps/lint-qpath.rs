@@ -0,0 +1,23 @@
+// check-pass
+// aux-build:lint-qpath.rs
+// ignore-stage1
+
+// Check that `check_qpath` is called for the qualified and type-relative paths, which
+// `check_path` doesn't see in full.
+
+#![feature(plugin)]
+#![plugin(lint_qpath)]
+//~^ WARN use of deprecated attribute `plugin`
+
+trait Tr {
+    const C: u8;
+}
+
+impl Tr for u8 {
+    const C: u8 = 0;
+}
+
+fn main() {
+    let _ = <u8 as Tr>::C; //~ WARN qualified path
+    let _ = u8::C; //~ WARN type-relative path
+}
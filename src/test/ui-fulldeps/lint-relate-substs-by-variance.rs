@@ -0,0 +1,27 @@
+// check-pass
+// aux-build:lint-relate-substs-by-variance.rs
+// ignore-stage1
+
+// Check that `relate_substs_by_variance` pairs every generic argument with its
+// variance, including equal ones, and has nothing to say about traits.
+
+#![feature(plugin)]
+#![plugin(lint_relate_substs_by_variance)]
+//~^ WARN use of deprecated attribute `plugin`
+#![allow(dead_code)]
+
+struct Foo<'a, T, U> {
+    x: &'a T,
+    f: fn(U),
+    cell: std::cell::Cell<U>,
+}
+
+trait Tr<T> {}
+
+fn foo(_: Foo<'static, u8, u8>, _: Foo<'static, u16, u8>) {}
+//~^ WARN are related by [(0, +), (1, +), (2, o)]
+
+fn tr(_: &dyn Tr<u8>, _: &dyn Tr<u16>) {}
+//~^ WARN `Tr` has no variances
+
+fn main() {}
@@ -0,0 +1,29 @@
+// check-pass
+// aux-build:lint-run-pass-on-item.rs
+// ignore-stage1
+
+// Check that `run_late_pass_on_item` runs the pass over the item and the items nested
+// in it, with the lint levels set on the item, and returns the pass.
+
+#![feature(plugin)]
+#![plugin(lint_run_pass_on_item)]
+//~^ WARN use of deprecated attribute `plugin`
+
+fn checked_a() {
+    //~^ WARN function `checked_a`
+    //~| WARN the pass run on `checked_a` visited 2 functions
+    fn inner() {} //~ WARN function `inner`
+    inner()
+}
+
+#[allow(item_fns)]
+fn checked_b() {
+    //~^ WARN the pass run on `checked_b` visited 2 functions
+    fn inner() {}
+    inner()
+}
+
+fn main() {
+    checked_a();
+    checked_b();
+}
@@ -0,0 +1,14 @@
+// check-pass
+// aux-build:lint-shared-values-count.rs
+// ignore-stage1
+
+// Check that the adjusted type of each expression is only looked up in the typeck
+// results once, by the pass sharing it, while three other passes read it.
+
+#![feature(plugin)]
+#![plugin(lint_shared_values_count)]
+//~^ WARN use of deprecated attribute `plugin`
+
+fn main() {
+    let _ = "a".to_string().len();
+}
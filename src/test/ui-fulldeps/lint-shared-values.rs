@@ -0,0 +1,16 @@
+// check-pass
+// aux-build:lint-shared-values.rs
+// ignore-stage1
+
+// Check that late lint passes can read the values shared about an expression
+// by other passes once the expression was checked by all of them.
+
+#![feature(plugin)]
+#![plugin(lint_shared_values)]
+//~^ WARN use of deprecated attribute `plugin`
+
+fn main() {
+    let _ = "a".to_string().len();
+    //~^ WARN method call of adjusted type `&String`
+    //~| WARN method call of adjusted type `usize`
+}
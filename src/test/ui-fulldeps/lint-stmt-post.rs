@@ -0,0 +1,16 @@
+// check-pass
+// aux-build:lint-stmt-post.rs
+// ignore-stage1
+
+// Check that `check_stmt_post` is called with the lint attributes of the statement.
+
+#![feature(plugin)]
+#![plugin(lint_stmt_post)]
+//~^ WARN use of deprecated attribute `plugin`
+
+fn main() {
+    let _a = 1; //~ WARN checked `let` statement
+
+    #[allow(let_stmt_post)]
+    let _b = 2;
+}
@@ -0,0 +1,18 @@
+// check-pass
+// aux-build:lint-typeck-results-for.rs
+// ignore-stage1
+
+// Check that `LateContext::typeck_results_for` returns the typeck results of the body
+// it is given, whether it is the current body, another body or there is none.
+
+#![feature(plugin)]
+#![plugin(lint_typeck_results_for)]
+//~^ WARN use of deprecated attribute `plugin`
+
+const C: u8 = 1; //~ WARN initializer of type `u8`
+
+fn main() {
+    let f = |x: u16| x; //~ WARN closure body of type `u16`
+    let _ = C + 1; //~ WARN constant initializer of type `u8`
+    f(0);
+}
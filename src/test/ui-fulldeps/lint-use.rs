@@ -0,0 +1,15 @@
+// check-pass
+// aux-build:lint-use.rs
+// ignore-stage1
+
+// Check that `check_use` is called for imports.
+
+#![feature(plugin)]
+#![plugin(lint_use)]
+//~^ WARN use of deprecated attribute `plugin`
+#![allow(unused_imports)]
+
+use std::collections::HashMap; //~ WARN Single import of `std::collections::HashMap`
+use std::io::*; //~ WARN Glob import of `std::io`
+
+fn main() {}
@@ -0,0 +1,21 @@
+// check-pass
+// aux-build:lint-where-predicate.rs
+// ignore-stage1
+
+#![feature(plugin)]
+#![plugin(lint_where_predicate)]
+//~^ WARN use of deprecated attribute `plugin`
+#![allow(dead_code)]
+
+fn foo<T>(_: T)
+where
+    T: Clone, //~ WARN where predicate #1
+    T: Copy, //~ WARN where predicate #2
+{
+}
+
+struct Bar<'a, T>(&'a T)
+where
+    T: 'a; //~ WARN where predicate #3
+
+fn main() {}
@@ -0,0 +1,17 @@
+// check-pass
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+// Checks that a constant unifies with a subtree near the root of a large bound,
+// whose deeper parts are never expanded.
+
+fn foo<const N: usize>() -> [u8; N + 1]
+where
+    [(); (N + 1) * (N * N + N * 2 + (N + 3) * (N + 4) - N / 2)]:,
+{
+    [0; N + 1]
+}
+
+fn main() {
+    let _ = foo::<3>();
+}
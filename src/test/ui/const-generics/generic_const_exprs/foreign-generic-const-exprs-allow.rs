@@ -0,0 +1,14 @@
+// aux-build:issue-94287-aux.rs
+// build-pass
+
+// Check that `foreign_generic_const_exprs` only rejects the crate at deny level.
+
+#![allow(foreign_generic_const_exprs)]
+
+extern crate issue_94287_aux;
+
+use std::str::FromStr;
+
+fn main() {
+    let _ = <issue_94287_aux::FixedI32<16>>::from_str("");
+}
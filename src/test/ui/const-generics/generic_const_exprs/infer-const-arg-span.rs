@@ -0,0 +1,14 @@
+// Check that the "type annotations needed" error for an unevaluatable constant
+// points at the const argument which could not be inferred.
+
+#![feature(generic_const_exprs, generic_arg_infer)]
+#![allow(incomplete_features)]
+
+fn foo<const N: usize>() -> usize where [u8; N + 1]: Sized {
+    N
+}
+
+fn main() {
+    foo::<_>();
+    //~^ ERROR type annotations needed
+}
@@ -0,0 +1,41 @@
+// check-pass
+// Checking the same `ConstEvaluatable` obligations over and over while
+// selecting between many candidates must not evaluate them each time.
+
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+trait Select<const N: usize> {
+    fn select() -> usize;
+}
+
+macro_rules! impls {
+    ($($n:literal)*) => {
+        $(
+            impl Select<$n> for [(); $n * 2 + 1] where [(); $n * 2 + 1]: Sized {
+                fn select() -> usize {
+                    $n
+                }
+            }
+        )*
+    };
+}
+
+impls!(0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31);
+
+fn chain<const N: usize>() -> usize
+where
+    [(); N * 2 + 1]: Select<N>,
+    [(); N + 1]: Sized,
+    [(); N + 2]: Sized,
+    [(); N + 3]: Sized,
+    [(); N + 4]: Sized,
+{
+    <[(); N * 2 + 1] as Select<N>>::select()
+}
+
+fn main() {
+    assert_eq!(chain::<0>(), 0);
+    assert_eq!(chain::<7>(), 7);
+    assert_eq!(chain::<31>(), 31);
+}
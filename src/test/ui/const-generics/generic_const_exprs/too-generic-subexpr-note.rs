@@ -0,0 +1,25 @@
+// Check that the part of a constant which is too generic to be evaluated is
+// pointed at, when it is not the constant as a whole.
+
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+use std::marker::PhantomData;
+use std::mem::size_of;
+
+struct Bounded<T, U>
+where
+    [(); size_of::<T>()]:,
+{
+    bytes: [u8; size_of::<T>() + size_of::<U>()],
+    //~^ ERROR unconstrained generic constant
+    marker: PhantomData<(T, U)>,
+}
+
+struct Unbounded<T, U> {
+    bytes: [u8; size_of::<T>() + size_of::<U>()],
+    //~^ ERROR unconstrained generic constant
+    marker: PhantomData<(T, U)>,
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+// Checks which constants are structurally equal to those of `ConstEvaluatable` bounds.
+
+struct Evaluatable<const N: u128>;
+
+struct Wrap<const N: usize>([u8; N + 1]) where [(); N + 1]:;
+
+struct Same<const N: usize>([u8; N + 1]) where [(); N + 1]:;
+
+struct Subtree<const N: usize>([u8; N + 1]) where [(); (N + 1) * 2]:;
+
+struct Commuted<const N: usize>([u8; 1 + N]) where [(); N + 1]:;
+//~^ ERROR unconstrained generic constant
+
+struct Different<const N: usize>([u8; N + 2]) where [(); N + 1]:;
+//~^ ERROR unconstrained generic constant
+
+// The casts are structurally equal except for their types.
+struct DifferentType<const N: u8>([u8; N as usize]) where Evaluatable<{ N as u128 }>:;
+//~^ ERROR unconstrained generic constant
+
+// The bound of `Wrap` becomes `{ M * 2 } + 1`, whose nested constant is only
+// expanded while unifying it with `M * 2 + 1`.
+fn nested<const M: usize>() -> Wrap<{ M * 2 }>
+where
+    [(); M * 2 + 1]:,
+{
+    Wrap([0; M * 2 + 1])
+}
+
+fn nested_different<const M: usize>() -> Wrap<{ M * 2 }>
+//~^ ERROR unconstrained generic constant
+where
+    [(); M * 2]:,
+    [(); M * 3 + 1]:,
+{
+    loop {}
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+// check-pass
+// run-rustfix
+
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features, dead_code)]
+
+fn only<const N: usize>() where [(); 3 + 1]: {}
+//~^ WARN this bound doesn't depend on any generic parameters
+
+fn first<const N: usize>() where [(); 2 * 2]:, [(); N + 1]: {}
+//~^ WARN this bound doesn't depend on any generic parameters
+
+fn last<const N: usize>() where [(); N + 1]:, [(); 2 + 2]: {}
+//~^ WARN this bound doesn't depend on any generic parameters
+
+fn generic<const N: usize>() where [(); N + 1]: {}
+
+fn main() {}
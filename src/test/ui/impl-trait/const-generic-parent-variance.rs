@@ -0,0 +1,12 @@
+// Check that `impl Trait` types are invariant wrt the const parameters of their
+// fn: unlike lifetimes, these are always captured, even if no bound mentions them.
+
+fn foo<const N: usize>() -> impl Sized {
+    [0u8; N]
+}
+
+fn main() {
+    let mut x = foo::<1>();
+    x = foo::<2>();
+    //~^ ERROR mismatched types
+}
@@ -0,0 +1,34 @@
+// Lifetimes in the substs of the bounds of an opaque type are invariant, even when
+// they are only used covariantly there, as in `impl Trait<&'a u8>`, so neither of
+// these opaque types can be shortened like the references they are made from.
+
+trait Trait<T> {}
+
+impl<T> Trait<T> for () {}
+
+// `'a: 'a` makes `'a` early-bound, so that it can be given explicitly.
+fn shared<'a>(_: &'a u8) -> impl Trait<&'a u8>
+where
+    'a: 'a,
+{
+}
+
+fn unique<'a>(_: &'a mut u8) -> impl Trait<&'a mut u8>
+where
+    'a: 'a,
+{
+}
+
+fn shorten_shared<'short, 'long: 'short>(short: &'short u8, long: &'long u8) {
+    let mut x = shared::<'short>(short);
+    //~^ ERROR lifetime may not live long enough
+    x = shared::<'long>(long);
+}
+
+fn shorten_unique<'short, 'long: 'short>(short: &'short mut u8, long: &'long mut u8) {
+    let mut x = unique::<'short>(short);
+    //~^ ERROR lifetime may not live long enough
+    x = unique::<'long>(long);
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+#![crate_type = "lib"]
+#![deny(invariant_raw_pointer_field)]
+#![allow(dead_code)]
+
+pub struct Exported<T> {
+    ptr: *mut T, //~ ERROR `Exported` is invariant in `T` because of the `*mut` pointer
+}
+
+pub struct Lifetime<'a> {
+    ptr: *mut &'a u8, //~ ERROR `Lifetime` is invariant in `'a` because of the `*mut` pointer
+}
+
+struct PublicField<T> {
+    pub ptr: *mut T, //~ ERROR `PublicField` is invariant in `T` because of the `*mut` pointer
+}
+
+// Private fields of types which aren't exported are fine.
+struct Private<T> {
+    ptr: *mut T,
+}
+
+// `T` would be invariant because of the `Cell` anyway.
+pub struct WithCell<T> {
+    ptr: *mut T,
+    cell: std::cell::Cell<T>,
+}
+
+pub struct ConstPtr<T> {
+    ptr: *const T,
+}
@@ -0,0 +1,24 @@
+// check-fail
+// compile-flags: -Z threads=4
+
+// Check that running the late lint passes in parallel emits the lints of every
+// module in a deterministic order, errors included: module by module, in `DefId` order.
+
+#![allow(dead_code)]
+#![deny(non_upper_case_globals)]
+
+fn Foo() {} //~ WARN function `Foo` should have a snake case name
+
+mod a {
+    static bar: u8 = 0; //~ ERROR static variable `bar` should have an upper case name
+
+    mod b {
+        fn Baz() {} //~ WARN function `Baz` should have a snake case name
+    }
+}
+
+mod c {
+    static qux: u8 = 0; //~ ERROR static variable `qux` should have an upper case name
+}
+
+fn main() {}
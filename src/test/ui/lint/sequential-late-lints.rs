@@ -0,0 +1,23 @@
+// check-pass
+// compile-flags: -Z sequential-late-lints
+
+// Check that running the late lint passes sequentially emits the lints of
+// every module, in the same order as running them in parallel.
+
+#![allow(dead_code)]
+
+fn Foo() {} //~ WARN function `Foo` should have a snake case name
+
+mod a {
+    fn Bar() {} //~ WARN function `Bar` should have a snake case name
+
+    mod b {
+        static baz: u8 = 0; //~ WARN static variable `baz` should have an upper case name
+    }
+}
+
+mod c {
+    fn Qux() {} //~ WARN function `Qux` should have a snake case name
+}
+
+fn main() {}
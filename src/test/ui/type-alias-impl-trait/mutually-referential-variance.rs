@@ -0,0 +1,29 @@
+// check-pass
+
+// Check that the variances of opaque types whose bounds mention each other
+// can be computed.
+
+#![feature(type_alias_impl_trait)]
+
+struct S;
+
+impl<T> PartialEq<T> for S {
+    fn eq(&self, _: &T) -> bool {
+        true
+    }
+}
+
+type A<'a> = impl PartialEq<B<'a>>;
+type B<'a> = impl PartialEq<A<'a>>;
+
+fn a<'a>() -> A<'a> {
+    S
+}
+
+fn b<'a>() -> B<'a> {
+    S
+}
+
+fn main() {
+    assert!(a() == b());
+}
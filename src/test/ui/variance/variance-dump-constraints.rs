@@ -0,0 +1,12 @@
+// Check the output of `-Z dump-variance-constraints`.
+
+// compile-flags: -Z dump-variance-constraints
+
+#![feature(rustc_attrs)]
+
+#[rustc_variance]
+struct Foo<'a, T> { //~ ERROR [-, +]
+    x: &'a T,
+}
+
+fn main() {}
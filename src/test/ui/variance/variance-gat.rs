@@ -0,0 +1,39 @@
+// Check that the parameters of generic associated types, and those of their trait
+// or impl, are invariant, whether the associated types are used covariantly or
+// invariantly in their bounds, the types impls define them as, or other types.
+
+#![feature(rustc_attrs)]
+
+use std::cell::Cell;
+use std::ops::Deref;
+
+trait Container<T> {
+    #[rustc_variance]
+    type Covariant<'a>: Deref<Target = &'a T> //~ ERROR [o, o, o]
+    where
+        T: 'a;
+
+    #[rustc_variance]
+    type Invariant<'a, U>: Deref<Target = Cell<&'a U>> //~ ERROR [o, o, o, o]
+    where
+        U: 'a;
+}
+
+impl<T> Container<T> for Vec<T> {
+    #[rustc_variance]
+    type Covariant<'a> = Box<&'a T> //~ ERROR [o, o]
+    where
+        T: 'a;
+
+    #[rustc_variance(a, U)]
+    type Invariant<'a, U> = Box<Cell<&'a U>> //~ ERROR 'a: o, U: o
+    where
+        U: 'a;
+}
+
+// Using a generic associated type covariantly doesn't make its parameters
+// variant either, as it's only used through a projection.
+#[rustc_variance]
+struct Holder<'a, C: Container<u8>>(C::Covariant<'a>); //~ ERROR [o, o]
+
+fn main() {}
@@ -0,0 +1,22 @@
+// Test that `#[rustc_variance(..)]` only reports the variances of the given parameters.
+
+#![feature(rustc_attrs)]
+
+#[rustc_variance(T)]
+struct OnlyT<'a, T, U> { //~ ERROR T: +
+    x: &'a T,
+    y: fn(U),
+}
+
+#[rustc_variance(U, a)]
+struct Several<'a, T, U> { //~ ERROR U: -, 'a: -
+    x: &'a T,
+    y: fn(U),
+}
+
+#[rustc_variance(V)] //~ ERROR no generic parameter named `V`
+struct Unknown<T> {
+    x: T,
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+// Test that `#[rustc_variance]` points out parameters which are only invariant
+// because `PhantomData` fields use them both covariantly and contravariantly.
+
+#![feature(rustc_attrs)]
+
+use std::marker::PhantomData;
+
+#[rustc_variance]
+struct Collision<T> { //~ ERROR [o]
+    co: PhantomData<T>,
+    contra: PhantomData<fn(T)>,
+}
+
+#[rustc_variance]
+struct LifetimeCollision<'a> { //~ ERROR [o]
+    co: PhantomData<fn(&'a ())>,
+    contra: PhantomData<&'a ()>,
+}
+
+#[rustc_variance]
+struct AllCovariant<T> { //~ ERROR [+]
+    a: PhantomData<T>,
+    b: PhantomData<Box<T>>,
+}
+
+#[rustc_variance]
+struct AlreadyInvariant<T> { //~ ERROR [o]
+    co: PhantomData<T>,
+    contra: PhantomData<fn(T)>,
+    inv: PhantomData<*mut T>,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+// Check the output of `-Z variance-solver-stats`.
+
+// compile-flags: -Z variance-solver-stats
+
+#![feature(rustc_attrs)]
+
+#[rustc_variance]
+struct Foo<'a, T> { //~ ERROR [-, +]
+    x: &'a T,
+}
+
+fn main() {}
@@ -0,0 +1,46 @@
+// Check that the variances of trait methods include those of the trait's
+// `Self` and parameters, as they are used by each method. Unused parameters
+// of fns are invariant.
+
+#![allow(dead_code)]
+#![feature(rustc_attrs)]
+
+trait Trait<'a, T> {
+    #[rustc_variance]
+    fn by_ref(&self); //~ ERROR [-, o, o]
+
+    #[rustc_variance]
+    fn by_mut(&mut self); //~ ERROR [o, o, o]
+
+    #[rustc_variance]
+    fn by_value(self) -> Self; //~ ERROR [o, o, o]
+
+    #[rustc_variance]
+    fn new(t: &'a T) -> Self; //~ ERROR [+, +, -]
+
+    #[rustc_variance]
+    fn replace(&mut self, f: fn(&'a mut T)) -> Option<&'a T>; //~ ERROR [o, -, o]
+
+    #[rustc_variance(Self)]
+    fn named<U>(&self, u: &mut U); //~ ERROR Self: -
+}
+
+struct Wrapper<X>(X);
+
+impl<X> Wrapper<X> {
+    #[rustc_variance]
+    fn make() -> Option<X> { //~ ERROR [+]
+        None
+    }
+
+    #[rustc_variance]
+    fn take(self) { //~ ERROR [-]
+    }
+
+    #[rustc_variance]
+    fn set(&mut self, x: X) { //~ ERROR [o]
+        self.0 = x;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+// Test that `#[rustc_variance]` on a type alias reports the variances of the
+// alias' generics as they are used in the aliased type.
+
+#![feature(rustc_attrs)]
+
+trait Trait<'a> {
+    type Assoc;
+}
+
+#[rustc_variance]
+type Ref<'a, T> = &'a T; //~ ERROR [-, +]
+
+#[rustc_variance]
+type RefMut<'a, T> = &'a mut T; //~ ERROR [-, o]
+
+#[rustc_variance]
+type Func<'a, T> = fn(&'a T) -> T; //~ ERROR [+, o]
+
+#[rustc_variance]
+type Proj<'a, T> = <T as Trait<'a>>::Assoc; //~ ERROR [o, o]
+
+#[rustc_variance]
+type Unused<'a> = u8; //~ ERROR [*]
+
+fn main() {}
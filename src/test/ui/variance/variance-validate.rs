@@ -0,0 +1,31 @@
+// Check that the variances inferred for mutually recursive items, which take
+// several rounds to solve, satisfy all their constraints.
+
+// check-pass
+// compile-flags: -Z validate-variance
+
+#![allow(dead_code)]
+
+use std::cell::Cell;
+use std::marker::PhantomData;
+
+struct List<'a, T> {
+    head: &'a T,
+    tail: Option<Box<Node<'a, T>>>,
+}
+
+struct Node<'a, T> {
+    list: List<'a, T>,
+    callback: fn(&'a T),
+}
+
+enum Tree<A, B> {
+    Leaf(Cell<A>),
+    Branch(Box<Tree<B, A>>, PhantomData<fn() -> B>),
+}
+
+fn takes_list<'a, T, U>(_list: List<'a, T>) -> Option<Tree<T, U>> {
+    None
+}
+
+fn main() {}
@@ -252,8 +252,8 @@ fn generate_output_example(&self, lint: &mut Lint) -> Result<(), Box<dyn Error>>
             lint.check_style()?;
         }
         // Unfortunately some lints have extra requirements that this simple test
-        // setup can't handle (like extern crates). An alternative is to use a
-        // separate test suite, and use an include mechanism such as mdbook's
+        // setup can't handle (like multiple extern crates). An alternative is to
+        // use a separate test suite, and use an include mechanism such as mdbook's
         // `{{#rustdoc_include}}`.
         if !lint.is_ignored() {
             if let Err(e) = self.replace_produces(lint) {
@@ -276,6 +276,8 @@ fn generate_output_example(&self, lint: &mut Lint) -> Result<(), Box<dyn Error>>
     /// output from the compiler.
     fn replace_produces(&self, lint: &mut Lint) -> Result<(), Box<dyn Error>> {
         let mut lines = lint.doc.iter_mut();
+        // The source of the `aux` crate, used by the examples following it.
+        let mut aux = None;
         loop {
             // Find start of example.
             let options = loop {
@@ -307,12 +309,22 @@ fn replace_produces(&self, lint: &mut Lint) -> Result<(), Box<dyn Error>> {
                     }
                 }
             }
+            // An auxiliary crate doesn't produce anything on its own.
+            if options.contains(&"aux") {
+                aux = Some(example.iter().map(|line| line.to_string()).collect::<Vec<_>>());
+                continue;
+            }
             // Find the {{produces}} line.
             loop {
                 match lines.next() {
                     Some(line) if line.is_empty() => {}
                     Some(line) if line == "{{produces}}" => {
-                        let output = self.generate_lint_output(&lint.name, &example, &options)?;
+                        let output = self.generate_lint_output(
+                            &lint.name,
+                            &example,
+                            &options,
+                            aux.as_deref(),
+                        )?;
                         line.replace_range(
                             ..,
                             &format!(
@@ -340,11 +352,15 @@ fn generate_lint_output(
         name: &str,
         example: &[&mut String],
         options: &[&str],
+        aux: Option<&[String]>,
     ) -> Result<String, Box<dyn Error>> {
         if self.verbose {
             eprintln!("compiling lint {}", name);
         }
         let tempdir = tempfile::TempDir::new()?;
+        if let Some(aux) = aux {
+            self.build_aux_crate(tempdir.path(), aux, options)?;
+        }
         let tempfile = tempdir.path().join("lint_example.rs");
         let mut source = String::new();
         let needs_main = !example.iter().any(|line| line.contains("fn main"));
@@ -383,6 +399,9 @@ fn generate_lint_output(
         if options.contains(&"test") {
             cmd.arg("--test");
         }
+        if aux.is_some() {
+            cmd.arg("--extern").arg("aux=libaux.rlib");
+        }
         cmd.arg("lint_example.rs");
         cmd.current_dir(tempdir.path());
         let output = cmd.output().map_err(|e| format!("failed to run command {:?}\n{}", cmd, e))?;
@@ -426,6 +445,42 @@ fn generate_lint_output(
         }
     }
 
+    /// Compiles the source of an `aux` code block to `libaux.rlib` in `dir`.
+    fn build_aux_crate(
+        &self,
+        dir: &Path,
+        aux: &[String],
+        options: &[&str],
+    ) -> Result<(), Box<dyn Error>> {
+        let mut source = String::new();
+        for line in aux {
+            source.push_str(line.strip_prefix("# ").unwrap_or(line));
+            source.push('\n');
+        }
+        let tempfile = dir.join("aux.rs");
+        fs::write(&tempfile, source)
+            .map_err(|e| format!("failed to write {}: {}", tempfile.display(), e))?;
+        let mut cmd = Command::new(self.rustc_path);
+        if options.contains(&"edition2015") {
+            cmd.arg("--edition=2015");
+        } else {
+            cmd.arg("--edition=2018");
+        }
+        cmd.arg("--crate-type=lib");
+        cmd.arg("--target").arg(self.rustc_target);
+        cmd.arg("aux.rs");
+        cmd.current_dir(dir);
+        let output = cmd.output().map_err(|e| format!("failed to run command {:?}\n{}", cmd, e))?;
+        if !output.status.success() {
+            return Err(format!(
+                "failed to build the auxiliary crate of the example:\n{}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+        Ok(())
+    }
+
     /// Saves the mdbook lint chapters at the given path.
     fn save_lints_markdown(&self, lints: &[Lint]) -> Result<(), Box<dyn Error>> {
         self.save_level(lints, Level::Allow, ALLOWED_MD)?;